@@ -0,0 +1,171 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::uint_flag;
+use seahorse::{Command, Context, Flag, FlagType};
+use std::num::IntErrorKind;
+
+pub fn base_command() -> Command {
+    Command::new("base")
+        .usage("oat base [number] [--from 2-36] [--to 2-36] [--no-group]")
+        .flag(Flag::new("from", FlagType::Uint).description("Base of the input (default: detected from 0x/0b/0o prefix, else 10)"))
+        .flag(Flag::new("to", FlagType::Uint).description("Base to convert to (default: show 2, 8, 10 and 16)"))
+        .flag(Flag::new("no-group", FlagType::Bool).description("Don't group digits in the output"))
+        .action(|c| exit_on_error(base_action(c)))
+}
+
+fn validate_base(base: usize) -> Result<u32, String> {
+    if (2..=36).contains(&base) {
+        Ok(base as u32)
+    } else {
+        Err(format!("Base must be between 2 and 36, got {}", base))
+    }
+}
+
+/// Strips a `0x`/`0b`/`0o` prefix, returning the remaining digits and the
+/// base the prefix implies.
+fn detect_prefix(input: &str) -> (&str, Option<u32>) {
+    let lower = input.to_ascii_lowercase();
+    for (prefix, base) in [("0x", 16), ("0b", 2), ("0o", 8)] {
+        if lower.starts_with(prefix) {
+            return (&input[2..], Some(base));
+        }
+    }
+    (input, None)
+}
+
+fn parse_number(input: &str, base: u32) -> Result<u128, String> {
+    let digits: String = input.chars().filter(|ch| *ch != '_').collect();
+    if digits.is_empty() {
+        return Err("Please provide a number".to_string());
+    }
+    if digits.starts_with('+') || digits.starts_with('-') {
+        return Err("Only non-negative integers are supported".to_string());
+    }
+
+    u128::from_str_radix(&digits, base).map_err(|e| match e.kind() {
+        IntErrorKind::PosOverflow => format!("'{}' is too large (maximum is {})", input, u128::MAX),
+        _ => format!("'{}' is not a valid base-{} number", input, base),
+    })
+}
+
+fn to_base(mut value: u128, base: u32) -> String {
+    if value == 0 {
+        return "0".to_string();
+    }
+
+    let mut digits = Vec::new();
+    while value > 0 {
+        let digit = (value % base as u128) as u32;
+        digits.push(std::char::from_digit(digit, base).unwrap_or('?'));
+        value /= base as u128;
+    }
+    digits.iter().rev().collect()
+}
+
+/// Groups digits from the right for readability: nibbles for binary and
+/// hex, triples for octal and decimal.
+fn group_digits(digits: &str, base: u32) -> String {
+    let (size, separator) = match base {
+        2 | 16 => (4, ' '),
+        8 => (3, ' '),
+        10 => (3, ','),
+        _ => return digits.to_string(),
+    };
+
+    let chars: Vec<char> = digits.chars().collect();
+    let mut grouped = String::new();
+    for (i, ch) in chars.iter().enumerate() {
+        if i > 0 && (chars.len() - i).is_multiple_of(size) {
+            grouped.push(separator);
+        }
+        grouped.push(*ch);
+    }
+    grouped
+}
+
+fn base_action(c: &Context) -> Result<(), OatError> {
+    let input = c
+        .args
+        .first()
+        .ok_or_else(|| OatError::Usage("Please provide a number".to_string()))?;
+    let (digits, detected) = detect_prefix(input);
+
+    let from = match uint_flag(c, "from")? {
+        Some(from) => validate_base(from).map_err(OatError::Usage)?,
+        None => detected.unwrap_or(10),
+    };
+    // Only strip the prefix when it agrees with the base, so "0b1" is still
+    // a valid base-16 number.
+    let digits = if detected == Some(from) { digits } else { input.as_str() };
+    let value = parse_number(digits, from).map_err(OatError::Usage)?;
+
+    let format = |base: u32| {
+        let digits = to_base(value, base);
+        if c.bool_flag("no-group") {
+            digits
+        } else {
+            group_digits(&digits, base)
+        }
+    };
+
+    match uint_flag(c, "to")? {
+        Some(to) => println!("{}", format(validate_base(to).map_err(OatError::Usage)?)),
+        None => {
+            println!("BIN: {}", format(2));
+            println!("OCT: {}", format(8));
+            println!("DEC: {}", format(10));
+            println!("HEX: {}", format(16));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_in_any_base() {
+        assert_eq!(parse_number("ff", 16), Ok(255));
+        assert_eq!(parse_number("FF", 16), Ok(255));
+        assert_eq!(parse_number("1010", 2), Ok(10));
+        assert_eq!(parse_number("1_000_000", 10), Ok(1_000_000));
+        assert_eq!(parse_number("z", 36), Ok(35));
+    }
+
+    #[test]
+    fn rejects_invalid_numbers() {
+        assert_eq!(parse_number("", 10), Err("Please provide a number".to_string()));
+        assert_eq!(parse_number("___", 10), Err("Please provide a number".to_string()));
+        assert_eq!(parse_number("-5", 10), Err("Only non-negative integers are supported".to_string()));
+        assert_eq!(parse_number("12", 2), Err("'12' is not a valid base-2 number".to_string()));
+        assert!(parse_number("f".repeat(33).as_str(), 16).unwrap_err().contains("too large"));
+    }
+
+    #[test]
+    fn detects_prefixes() {
+        assert_eq!(detect_prefix("0xFF"), ("FF", Some(16)));
+        assert_eq!(detect_prefix("0B101"), ("101", Some(2)));
+        assert_eq!(detect_prefix("0o17"), ("17", Some(8)));
+        assert_eq!(detect_prefix("42"), ("42", None));
+    }
+
+    #[test]
+    fn groups_digits_from_the_right() {
+        assert_eq!(group_digits("11111111", 2), "1111 1111");
+        assert_eq!(group_digits("101", 2), "101");
+        assert_eq!(group_digits("1234567", 10), "1,234,567");
+        assert_eq!(group_digits("123", 10), "123");
+        assert_eq!(group_digits("deadbeef1", 16), "d eadb eef1");
+        assert_eq!(group_digits("7777", 8), "7 777");
+        assert_eq!(group_digits("zzzz", 36), "zzzz");
+        assert_eq!(group_digits("", 10), "");
+    }
+
+    #[test]
+    fn converts_between_bases() {
+        assert_eq!(to_base(0, 2), "0");
+        assert_eq!(to_base(255, 16), "ff");
+        assert_eq!(to_base(u128::MAX, 16), "f".repeat(32));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::string_flag;
+use crate::util::input::read_text;
+use seahorse::{Command, Context, Flag, FlagType};
+
+pub fn case_command() -> Command {
+    Command::new("case")
+        .usage(r#"oat case "[text]" --to snake|camel|pascal|kebab|upper|lower|title"#)
+        .flag(Flag::new("to", FlagType::String).alias("t").description("Target case: snake, camel, pascal, kebab, upper, lower or title"))
+        .action(|c| exit_on_error(case_action(c)))
+}
+
+/// Splits an identifier or phrase into words on separators, lower-to-upper
+/// transitions and the end of acronyms, so "HTTPServer" becomes
+/// ["HTTP", "Server"].
+fn split_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+
+    for part in text.split(|ch: char| !ch.is_alphanumeric()).filter(|p| !p.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut current = String::new();
+
+        for (i, &ch) in chars.iter().enumerate() {
+            if i > 0 && ch.is_uppercase() {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+                if !prev.is_uppercase() || next_is_lower {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            current.push(ch);
+        }
+
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+fn to_snake(text: &str) -> String {
+    split_words(text).iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_")
+}
+
+fn to_kebab(text: &str) -> String {
+    split_words(text).iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("-")
+}
+
+fn to_pascal(text: &str) -> String {
+    split_words(text).iter().map(|w| capitalize(w)).collect()
+}
+
+fn to_camel(text: &str) -> String {
+    split_words(text)
+        .iter()
+        .enumerate()
+        .map(|(i, w)| if i == 0 { w.to_lowercase() } else { capitalize(w) })
+        .collect()
+}
+
+fn to_title(text: &str) -> String {
+    split_words(text).iter().map(|w| capitalize(w)).collect::<Vec<_>>().join(" ")
+}
+
+fn to_upper(text: &str) -> String {
+    text.to_uppercase()
+}
+
+fn to_lower(text: &str) -> String {
+    text.to_lowercase()
+}
+
+fn case_action(c: &Context) -> Result<(), OatError> {
+    let to = string_flag(c, "to")?.ok_or_else(|| {
+        OatError::Usage("Please choose a case with --to (snake, camel, pascal, kebab, upper, lower, title)".to_string())
+    })?;
+
+    let convert: fn(&str) -> String = match to.as_str() {
+        "snake" => to_snake,
+        "camel" => to_camel,
+        "pascal" => to_pascal,
+        "kebab" => to_kebab,
+        "upper" => to_upper,
+        "lower" => to_lower,
+        "title" => to_title,
+        _ => return Err(OatError::Usage(format!("Unknown case '{}'", to))),
+    };
+
+    let text = read_text(&c.args)?;
+    for line in text.lines() {
+        println!("{}", convert(line));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_acronyms() {
+        assert_eq!(split_words("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(split_words("getHTTPResponseCode"), ["get", "HTTP", "Response", "Code"]);
+        assert_eq!(split_words("XMLHttpRequest2"), ["XML", "Http", "Request2"]);
+    }
+
+    #[test]
+    fn splits_on_separators() {
+        assert_eq!(split_words("hello_world-foo bar.baz"), ["hello", "world", "foo", "bar", "baz"]);
+        assert_eq!(split_words("  __leading and trailing__  "), ["leading", "and", "trailing"]);
+    }
+
+    #[test]
+    fn converts_acronyms() {
+        assert_eq!(to_snake("HTTPServer"), "http_server");
+        assert_eq!(to_kebab("getHTTPResponseCode"), "get-http-response-code");
+        assert_eq!(to_camel("HTTPServer"), "httpServer");
+        assert_eq!(to_pascal("getHTTPResponseCode"), "GetHttpResponseCode");
+        assert_eq!(to_title("XMLHttpRequest2"), "Xml Http Request2");
+    }
+
+    #[test]
+    fn converts_multi_word_phrases() {
+        assert_eq!(to_snake("Hello big World"), "hello_big_world");
+        assert_eq!(to_camel("hello-big_world"), "helloBigWorld");
+        assert_eq!(to_pascal("hello big world"), "HelloBigWorld");
+        assert_eq!(to_kebab("HelloBigWorld"), "hello-big-world");
+        assert_eq!(to_title("hello_big_world"), "Hello Big World");
+        assert_eq!(to_snake("version 2 api"), "version_2_api");
+    }
+
+    #[test]
+    fn handles_empty_input() {
+        assert!(split_words("").is_empty());
+        assert!(split_words("-_ ").is_empty());
+        assert_eq!(to_snake(""), "");
+        assert_eq!(to_camel(""), "");
+        assert_eq!(to_pascal(""), "");
+        assert_eq!(to_kebab(""), "");
+        assert_eq!(to_title(""), "");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::string_flag;
+use crate::util::input::read_text;
+use seahorse::{Command, Context, Flag, FlagType};
+use unicode_general_category::get_general_category;
+
+pub fn char_command() -> Command {
+    Command::new("char")
+        .usage(r#"oat char "[characters]" [--from-code 0x1F600]"#)
+        .flag(Flag::new("from-code", FlagType::String).alias("c").description("Look up a codepoint given as U+XXXX, 0xXXXX or decimal"))
+        .action(|c| exit_on_error(char_action(c)))
+}
+
+fn parse_codepoint(code: &str) -> Result<char, String> {
+    let code = code.trim();
+    let hex = code
+        .strip_prefix("U+")
+        .or_else(|| code.strip_prefix("u+"))
+        .or_else(|| code.strip_prefix("0x"))
+        .or_else(|| code.strip_prefix("0X"));
+    let value = match hex {
+        Some(digits) => u32::from_str_radix(digits, 16),
+        None => code.parse::<u32>(),
+    }
+    .map_err(|_| format!("Invalid codepoint '{}'", code))?;
+
+    char::from_u32(value).ok_or_else(|| format!("U+{:04X} is not a valid Unicode scalar value", value))
+}
+
+/// Turns a category variant such as `OtherSymbol` into "Other Symbol".
+fn category_name(ch: char) -> String {
+    let category = get_general_category(ch);
+    let mut name = String::new();
+    for (i, letter) in format!("{:?}", category).chars().enumerate() {
+        if i > 0 && letter.is_uppercase() {
+            name.push(' ');
+        }
+        name.push(letter);
+    }
+    format!("{} ({})", category.abbreviation(), name)
+}
+
+fn print_info(ch: char) {
+    let display = if ch.is_control() || ch.is_whitespace() {
+        ch.escape_default().to_string()
+    } else {
+        ch.to_string()
+    };
+    let name = unicode_names2::name(ch).map(|name| name.to_string()).unwrap_or_else(|| "(unnamed)".to_string());
+
+    let mut utf8 = [0u8; 4];
+    let utf8: Vec<String> = ch.encode_utf8(&mut utf8).bytes().map(|byte| format!("{:02X}", byte)).collect();
+    let mut utf16 = [0u16; 2];
+    let utf16: Vec<String> = ch.encode_utf16(&mut utf16).iter().map(|unit| format!("{:04X}", unit)).collect();
+
+    println!("Character: {}", display);
+    println!("Codepoint: U+{:04X} ({})", ch as u32, ch as u32);
+    println!("Name:      {}", name);
+    println!("Category:  {}", category_name(ch));
+    println!("UTF-8:     {}", utf8.join(" "));
+    println!("UTF-16:    {}", utf16.join(" "));
+}
+
+fn char_action(c: &Context) -> Result<(), OatError> {
+    let chars: Vec<char> = match string_flag(c, "from-code")? {
+        Some(code) => vec![parse_codepoint(&code).map_err(OatError::Usage)?],
+        None => {
+            let text = read_text(&c.args)?;
+            // Piped input usually ends with a newline that isn't part of the query.
+            let text = if c.args.is_empty() { text.strip_suffix('\n').unwrap_or(&text).to_string() } else { text };
+            text.chars().collect()
+        }
+    };
+
+    if chars.is_empty() {
+        return Err(OatError::Usage("Please provide a character to look up".to_string()));
+    }
+
+    for (i, ch) in chars.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print_info(ch);
+    }
+
+    Ok(())
+}
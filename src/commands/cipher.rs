@@ -0,0 +1,100 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::int_flag;
+use crate::util::input::read_text;
+use seahorse::{Command, Context, Flag, FlagType};
+
+pub fn cipher_command() -> Command {
+    Command::new("cipher")
+        .usage("oat cipher [rot13|caesar]")
+        .command(rot13_command())
+        .command(caesar_command())
+}
+
+fn decode_flag() -> Flag {
+    Flag::new("decode", FlagType::Bool)
+        .alias("d")
+        .description("Reverse the shift")
+}
+
+fn rot13_command() -> Command {
+    Command::new("rot13")
+        .usage(r#"oat cipher rot13 "[text]" [--decode]"#)
+        .flag(decode_flag())
+        .action(|c| exit_on_error(cipher_action(c, 13)))
+}
+
+fn caesar_command() -> Command {
+    Command::new("caesar")
+        .usage(r#"oat cipher caesar --shift N "[text]" [--decode]"#)
+        .flag(Flag::new("shift", FlagType::Int).alias("s").description("Number of positions to shift letters by (default: 3)"))
+        .flag(decode_flag())
+        .action(|c| exit_on_error(caesar_action(c)))
+}
+
+fn caesar_action(c: &Context) -> Result<(), OatError> {
+    let shift = int_flag(c, "shift")?.unwrap_or(3);
+    cipher_action(c, shift as i64)
+}
+
+fn cipher_action(c: &Context, shift: i64) -> Result<(), OatError> {
+    let text = read_text(&c.args)?;
+    println!("{}", apply_shift(text.trim_end_matches('\n'), shift, c.bool_flag("decode")));
+
+    Ok(())
+}
+
+/// Encodes with `shift`, or decodes by shifting the other way.
+fn apply_shift(text: &str, shift: i64, decode: bool) -> String {
+    shift_text(text, if decode { -shift } else { shift })
+}
+
+/// Shifts ASCII letters by `shift` positions, wrapping around the alphabet
+/// and leaving every other character untouched.
+fn shift_text(text: &str, shift: i64) -> String {
+    let shift = shift.rem_euclid(26) as u8;
+    text.chars()
+        .map(|ch| {
+            let base = match ch {
+                'a'..='z' => b'a',
+                'A'..='Z' => b'A',
+                _ => return ch,
+            };
+            ((ch as u8 - base + shift) % 26 + base) as char
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rot13_round_trips() {
+        assert_eq!(shift_text("Hello, World!", 13), "Uryyb, Jbeyq!");
+        assert_eq!(shift_text(&shift_text("Hello, World!", 13), 13), "Hello, World!");
+        assert_eq!(shift_text("The Quick Brown Fox", 13), "Gur Dhvpx Oebja Sbk");
+    }
+
+    #[test]
+    fn caesar_wraps_negative_and_large_shifts() {
+        assert_eq!(shift_text("abc xyz", 3), "def abc");
+        assert_eq!(shift_text("abc", -1), "zab");
+        assert_eq!(shift_text("ABC", -27), "ZAB");
+        assert_eq!(shift_text("abc", 29), "def");
+        assert_eq!(shift_text("abc", 26), "abc");
+    }
+
+    #[test]
+    fn decode_reverses_encode() {
+        let encoded = apply_shift("Attack at dawn", 5, false);
+        assert_eq!(encoded, "Fyyfhp fy ifbs");
+        assert_eq!(apply_shift(&encoded, 5, true), "Attack at dawn");
+        assert_eq!(apply_shift("zab", -1, true), "abc");
+    }
+
+    #[test]
+    fn passes_non_ascii_through() {
+        assert_eq!(shift_text("héllo wörld 123 ✓", 1), "iémmp xösme 123 ✓");
+        assert_eq!(shift_text("日本語", 13), "日本語");
+    }
+}
@@ -0,0 +1,225 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::string_flag;
+use crate::util::output::{is_quiet, should_colorize};
+use seahorse::{Command, Context, Flag, FlagType};
+
+pub fn color_command() -> Command {
+    Command::new("color")
+        .usage(r#"oat color "[#rrggbb|rgb(r,g,b)|hsl(h,s%,l%)]" [--to hex|rgb|hsl]"#)
+        .flag(Flag::new("to", FlagType::String).description("Only print the given format: hex, rgb or hsl"))
+        .action(|c| exit_on_error(color_action(c)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+fn parse_hex(value: &str) -> Result<Rgb, String> {
+    let hex = value.trim_start_matches('#');
+    if !hex.is_ascii() {
+        return Err(format!("Invalid hex color '{}'", value));
+    }
+
+    let expanded: String = match hex.len() {
+        3 => hex.chars().flat_map(|ch| [ch, ch]).collect(),
+        6 => hex.to_string(),
+        _ => return Err(format!("Invalid hex color '{}': expected #rgb or #rrggbb", value)),
+    };
+
+    let channel = |i: usize| {
+        u8::from_str_radix(&expanded[i..i + 2], 16)
+            .map_err(|_| format!("Invalid hex color '{}'", value))
+    };
+
+    Ok(Rgb {
+        r: channel(0)?,
+        g: channel(2)?,
+        b: channel(4)?,
+    })
+}
+
+/// Splits the comma-separated arguments of a functional notation such as
+/// `rgb(1, 2, 3)`, returning `None` if `value` doesn't use `name`.
+fn function_args<'a>(value: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let inner = value.strip_prefix(name)?.strip_prefix('(')?.strip_suffix(')')?;
+    Some(inner.split(',').map(str::trim).collect())
+}
+
+fn parse_rgb(args: &[&str], value: &str) -> Result<Rgb, String> {
+    let channels: Vec<u8> = args
+        .iter()
+        .map(|arg| arg.parse::<u8>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| format!("Invalid rgb color '{}': channels must be 0-255", value))?;
+
+    match channels.as_slice() {
+        [r, g, b] => Ok(Rgb { r: *r, g: *g, b: *b }),
+        _ => Err(format!("Invalid rgb color '{}': expected 3 channels", value)),
+    }
+}
+
+fn parse_hsl(args: &[&str], value: &str) -> Result<Rgb, String> {
+    let invalid = || format!("Invalid hsl color '{}': expected hsl(0-360, 0-100%, 0-100%)", value);
+    if args.len() != 3 {
+        return Err(invalid());
+    }
+
+    let h: f64 = args[0].trim_end_matches("deg").parse().map_err(|_| invalid())?;
+    let s: f64 = args[1].trim_end_matches('%').parse().map_err(|_| invalid())?;
+    let l: f64 = args[2].trim_end_matches('%').parse().map_err(|_| invalid())?;
+    if !(0.0..=100.0).contains(&s) || !(0.0..=100.0).contains(&l) {
+        return Err(invalid());
+    }
+
+    Ok(hsl_to_rgb(h.rem_euclid(360.0), s / 100.0, l / 100.0))
+}
+
+fn parse_color(value: &str) -> Result<Rgb, String> {
+    let value = value.trim().to_lowercase();
+    if let Some(args) = function_args(&value, "rgb") {
+        parse_rgb(&args, &value)
+    } else if let Some(args) = function_args(&value, "hsl") {
+        parse_hsl(&args, &value)
+    } else {
+        parse_hex(&value)
+    }
+}
+
+fn hsl_to_rgb(h: f64, s: f64, l: f64) -> Rgb {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+
+    let (r, g, b) = match h as u32 {
+        0..=59 => (c, x, 0.0),
+        60..=119 => (x, c, 0.0),
+        120..=179 => (0.0, c, x),
+        180..=239 => (0.0, x, c),
+        240..=299 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    let channel = |v: f64| ((v + m) * 255.0).round() as u8;
+    Rgb {
+        r: channel(r),
+        g: channel(g),
+        b: channel(b),
+    }
+}
+
+fn rgb_to_hsl(color: Rgb) -> (f64, f64, f64) {
+    let r = color.r as f64 / 255.0;
+    let g = color.g as f64 / 255.0;
+    let b = color.b as f64 / 255.0;
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+
+    if d == 0.0 {
+        return (0.0, 0.0, l * 100.0);
+    }
+
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        60.0 * ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+
+    (h, s * 100.0, l * 100.0)
+}
+
+fn format_hex(color: Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+fn format_rgb(color: Rgb) -> String {
+    format!("rgb({}, {}, {})", color.r, color.g, color.b)
+}
+
+fn format_hsl(color: Rgb) -> String {
+    let (h, s, l) = rgb_to_hsl(color);
+    format!("hsl({:.0}, {:.0}%, {:.0}%)", h, s, l)
+}
+
+fn color_action(c: &Context) -> Result<(), OatError> {
+    let value = c.args.join(" ");
+    if value.is_empty() {
+        return Err(OatError::Usage("Please provide a color".to_string()));
+    }
+
+    let color = parse_color(&value).map_err(OatError::Usage)?;
+
+    if let Some(to) = string_flag(c, "to")? {
+        let formatted = match to.as_str() {
+            "hex" => format_hex(color),
+            "rgb" => format_rgb(color),
+            "hsl" => format_hsl(color),
+            _ => return Err(OatError::Usage(format!("Unknown format '{}'. Use hex, rgb or hsl", to))),
+        };
+        println!("{}", formatted);
+        return Ok(());
+    }
+
+    if should_colorize() && !is_quiet() {
+        println!("\x1b[48;2;{};{};{}m        \x1b[0m", color.r, color.g, color.b);
+    }
+    println!("HEX: {}", format_hex(color));
+    println!("RGB: {}", format_rgb(color));
+    println!("HSL: {}", format_hsl(color));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = Rgb { r: 255, g: 0, b: 0 };
+
+    #[test]
+    fn parses_hex_colors() {
+        assert_eq!(parse_color("#ff0000"), Ok(RED));
+        assert_eq!(parse_color("F00"), Ok(RED));
+        assert_eq!(parse_color("  #FF0000 "), Ok(RED));
+        assert!(parse_color("#ff00").is_err());
+        assert!(parse_color("#gg0000").is_err());
+        assert!(parse_color("#ffé00").is_err());
+    }
+
+    #[test]
+    fn parses_rgb_and_hsl_notation() {
+        assert_eq!(parse_color("rgb(255, 0, 0)"), Ok(RED));
+        assert_eq!(parse_color("RGB(255,0,0)"), Ok(RED));
+        assert_eq!(parse_color("hsl(0, 100%, 50%)"), Ok(RED));
+        assert_eq!(parse_color("hsl(360deg, 100%, 50%)"), Ok(RED));
+        assert!(parse_color("rgb(256, 0, 0)").is_err());
+        assert!(parse_color("rgb(1, 2)").is_err());
+        assert!(parse_color("hsl(0, 101%, 50%)").is_err());
+    }
+
+    #[test]
+    fn converts_hsl_to_rgb() {
+        assert_eq!(hsl_to_rgb(0.0, 0.0, 0.0), Rgb { r: 0, g: 0, b: 0 });
+        assert_eq!(hsl_to_rgb(0.0, 0.0, 1.0), Rgb { r: 255, g: 255, b: 255 });
+        assert_eq!(hsl_to_rgb(120.0, 1.0, 0.5), Rgb { r: 0, g: 255, b: 0 });
+        assert_eq!(hsl_to_rgb(240.0, 1.0, 0.5), Rgb { r: 0, g: 0, b: 255 });
+        assert_eq!(hsl_to_rgb(210.0, 0.5, 0.4), Rgb { r: 51, g: 102, b: 153 });
+        assert_eq!(hsl_to_rgb(359.5, 1.0, 0.5), Rgb { r: 255, g: 0, b: 2 });
+    }
+
+    #[test]
+    fn round_trips_through_hsl() {
+        for color in [RED, Rgb { r: 51, g: 102, b: 153 }, Rgb { r: 18, g: 52, b: 86 }] {
+            let (h, s, l) = rgb_to_hsl(color);
+            assert_eq!(hsl_to_rgb(h, s / 100.0, l / 100.0), color);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::config::{get_config_file_path, load_config, save_config, Config};
+use crate::error::{exit_on_error, OatError};
+use seahorse::{Command, Context};
+use serde_json::{Map, Value};
+
+pub fn config_command() -> Command {
+    Command::new("config")
+        .usage("oat config [get|set|path|list]")
+        .command(get_command())
+        .command(set_command())
+        .command(path_command())
+        .command(list_command())
+}
+
+fn get_command() -> Command {
+    Command::new("get")
+        .usage("oat config get [key]")
+        .action(|c| exit_on_error(get_action(c)))
+}
+
+fn set_command() -> Command {
+    Command::new("set")
+        .usage("oat config set [key] [value]")
+        .action(|c| exit_on_error(set_action(c)))
+}
+
+fn path_command() -> Command {
+    Command::new("path")
+        .usage("oat config path")
+        .action(|_| println!("{}", get_config_file_path().display()))
+}
+
+fn list_command() -> Command {
+    Command::new("list")
+        .usage("oat config list")
+        .action(|_| exit_on_error(list_action()))
+}
+
+/// Returns the config as a key/value map so settings can be read and
+/// written by name without listing every field here.
+fn config_to_map(config: &Config) -> Result<Map<String, Value>, OatError> {
+    match serde_json::to_value(config) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(OatError::Failed("Config is not an object".to_string())),
+        Err(e) => Err(OatError::Failed(format!("Failed to serialize config: {}", e))),
+    }
+}
+
+fn unknown_key(key: &str, map: &Map<String, Value>) -> OatError {
+    let keys: Vec<&str> = map.keys().map(String::as_str).collect();
+    OatError::Usage(format!("Unknown key '{}'. Available keys: {}", key, keys.join(", ")))
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn get_action(c: &Context) -> Result<(), OatError> {
+    let key = c
+        .args
+        .first()
+        .ok_or_else(|| OatError::Usage("Please provide a key".to_string()))?;
+
+    let map = config_to_map(&load_config()?)?;
+    let value = map.get(key).ok_or_else(|| unknown_key(key, &map))?;
+    println!("{}", format_value(value));
+
+    Ok(())
+}
+
+fn set_action(c: &Context) -> Result<(), OatError> {
+    let (key, raw) = match c.args.as_slice() {
+        [key, value] => (key, value),
+        _ => return Err(OatError::Usage("Usage: oat config set [key] [value]".to_string())),
+    };
+
+    let mut map = config_to_map(&load_config()?)?;
+    if !map.contains_key(key) {
+        return Err(unknown_key(key, &map));
+    }
+
+    // Values are parsed as JSON first so `true` and `3` get their natural
+    // types, falling back to a plain string.
+    let value = serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.clone()));
+    map.insert(key.clone(), value);
+
+    let config: Config = serde_json::from_value(Value::Object(map))
+        .map_err(|e| OatError::Usage(format!("Invalid value '{}' for {}: {}", raw, key, e)))?;
+    save_config(&config)?;
+    println!("{} = {}", key, raw);
+
+    Ok(())
+}
+
+fn list_action() -> Result<(), OatError> {
+    let map = config_to_map(&load_config()?)?;
+    for (key, value) in &map {
+        println!("{} = {}", key, format_value(value));
+    }
+
+    Ok(())
+}
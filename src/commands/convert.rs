@@ -0,0 +1,112 @@
+use crate::error::{exit_on_error, OatError};
+use seahorse::{Command, Context};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dimension {
+    Length,
+    Weight,
+    Volume,
+}
+
+/// Unit name, dimension and factor to the dimension's base unit (meter,
+/// kilogram and liter).
+const UNITS: &[(&str, Dimension, f64)] = &[
+    ("mm", Dimension::Length, 0.001),
+    ("cm", Dimension::Length, 0.01),
+    ("m", Dimension::Length, 1.0),
+    ("km", Dimension::Length, 1000.0),
+    ("in", Dimension::Length, 0.0254),
+    ("ft", Dimension::Length, 0.3048),
+    ("yd", Dimension::Length, 0.9144),
+    ("mi", Dimension::Length, 1609.344),
+    ("nmi", Dimension::Length, 1852.0),
+    ("mg", Dimension::Weight, 0.000001),
+    ("g", Dimension::Weight, 0.001),
+    ("kg", Dimension::Weight, 1.0),
+    ("t", Dimension::Weight, 1000.0),
+    ("oz", Dimension::Weight, 0.028349523125),
+    ("lb", Dimension::Weight, 0.45359237),
+    ("st", Dimension::Weight, 6.35029318),
+    ("ml", Dimension::Volume, 0.001),
+    ("l", Dimension::Volume, 1.0),
+    ("m3", Dimension::Volume, 1000.0),
+    ("tsp", Dimension::Volume, 0.00492892159375),
+    ("tbsp", Dimension::Volume, 0.01478676478125),
+    ("floz", Dimension::Volume, 0.0295735295625),
+    ("cup", Dimension::Volume, 0.2365882365),
+    ("pt", Dimension::Volume, 0.473176473),
+    ("qt", Dimension::Volume, 0.946352946),
+    ("gal", Dimension::Volume, 3.785411784),
+];
+
+const TEMPERATURES: &[&str] = &["c", "f", "k"];
+
+pub fn convert_command() -> Command {
+    Command::new("convert")
+        .usage("oat convert [amount] [from] [to]  e.g. oat convert 100 C F, oat convert 5 km mi")
+        .action(|c| exit_on_error(convert_action(c)))
+}
+
+fn format_number(value: f64) -> String {
+    let formatted = format!("{:.6}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" {
+        "0".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Converts between Celsius, Fahrenheit and Kelvin, returning the result and
+/// the formula that was applied.
+fn convert_temperature(amount: f64, from: &str, to: &str) -> (f64, &'static str) {
+    match (from, to) {
+        ("c", "f") => (amount * 9.0 / 5.0 + 32.0, "F = C × 9/5 + 32"),
+        ("f", "c") => ((amount - 32.0) * 5.0 / 9.0, "C = (F − 32) × 5/9"),
+        ("c", "k") => (amount + 273.15, "K = C + 273.15"),
+        ("k", "c") => (amount - 273.15, "C = K − 273.15"),
+        ("f", "k") => ((amount - 32.0) * 5.0 / 9.0 + 273.15, "K = (F − 32) × 5/9 + 273.15"),
+        ("k", "f") => ((amount - 273.15) * 9.0 / 5.0 + 32.0, "F = (K − 273.15) × 9/5 + 32"),
+        _ => (amount, "no conversion needed"),
+    }
+}
+
+fn find_unit(name: &str) -> Option<(Dimension, f64)> {
+    UNITS
+        .iter()
+        .find(|(unit, _, _)| *unit == name)
+        .map(|(_, dimension, factor)| (*dimension, *factor))
+}
+
+fn convert_action(c: &Context) -> Result<(), OatError> {
+    let (amount, from, to) = match c.args.as_slice() {
+        [amount, from, to] => (amount, from, to),
+        _ => return Err(OatError::Usage("Usage: oat convert [amount] [from] [to]".to_string())),
+    };
+
+    let value: f64 = amount
+        .parse()
+        .map_err(|_| OatError::Usage(format!("Invalid amount: {}", amount)))?;
+    let from_key = from.to_lowercase();
+    let to_key = to.to_lowercase();
+
+    if TEMPERATURES.contains(&from_key.as_str()) && TEMPERATURES.contains(&to_key.as_str()) {
+        let (result, formula) = convert_temperature(value, &from_key, &to_key);
+        println!("{} {} = {} {}", format_number(value), from, format_number(result), to);
+        println!("Formula: {}", formula);
+        return Ok(());
+    }
+
+    let unknown = |unit: &str| OatError::Usage(format!("Unknown unit '{}'", unit));
+    let (from_dimension, from_factor) = find_unit(&from_key).ok_or_else(|| unknown(from))?;
+    let (to_dimension, to_factor) = find_unit(&to_key).ok_or_else(|| unknown(to))?;
+    if from_dimension != to_dimension {
+        return Err(OatError::Usage(format!("Cannot convert {} to {}", from, to)));
+    }
+
+    let factor = from_factor / to_factor;
+    println!("{} {} = {} {}", format_number(value), from, format_number(value * factor), to);
+    println!("Formula: {} = {} × {}", to, from, format_number(factor));
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::{string_flag, uint_flag};
+use crate::util::input::{read_file, read_text};
+use seahorse::{Command, Context, Flag, FlagType};
+use std::collections::HashMap;
+
+pub fn count_command() -> Command {
+    Command::new("count")
+        .usage(r#"oat count "[text]" [--file path] [--top N]"#)
+        .flag(Flag::new("file", FlagType::String).alias("f").description("Count the contents of a file"))
+        .flag(Flag::new("top", FlagType::Uint).description("Also list the N most frequent words"))
+        .action(|c| exit_on_error(count_action(c)))
+}
+
+fn words(text: &str) -> Vec<&str> {
+    text.split_whitespace().collect()
+}
+
+/// Returns the `n` most frequent words, compared case-insensitively with
+/// surrounding punctuation removed. Ties are ordered alphabetically.
+fn top_words(words: &[&str], n: usize) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for word in words {
+        let normalized = word.trim_matches(|ch: char| !ch.is_alphanumeric()).to_lowercase();
+        if !normalized.is_empty() {
+            *counts.entry(normalized).or_insert(0) += 1;
+        }
+    }
+
+    let mut sorted: Vec<(String, usize)> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    sorted.truncate(n);
+    sorted
+}
+
+fn count_action(c: &Context) -> Result<(), OatError> {
+    let text = match string_flag(c, "file")? {
+        Some(path) => read_file(&path)?,
+        None => read_text(&c.args)?,
+    };
+
+    let words = words(&text);
+    let word_chars: usize = words.iter().map(|w| w.chars().count()).sum();
+    let average = if words.is_empty() {
+        0.0
+    } else {
+        word_chars as f64 / words.len() as f64
+    };
+
+    println!("Lines:      {}", text.lines().count());
+    println!("Words:      {}", words.len());
+    println!("Characters: {}", text.chars().count());
+    println!("Bytes:      {}", text.len());
+    println!("Avg. word:  {:.2} characters", average);
+
+    if let Some(n) = uint_flag(c, "top")? {
+        println!();
+        println!("Top {} words:", n);
+        for (word, count) in top_words(&words, n) {
+            println!("{:>6}  {}", count, word);
+        }
+    }
+
+    Ok(())
+}
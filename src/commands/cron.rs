@@ -0,0 +1,361 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::uint_flag;
+use chrono::{DateTime, Local, TimeZone};
+use cron::Schedule;
+use seahorse::{Command, Context, Flag, FlagType};
+use std::str::FromStr;
+
+const MONTHS: &[&str] = &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const MONTH_NAMES: &[&str] = &[
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+const DAYS: &[&str] = &["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+const DAY_NAMES: &[&str] = &["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+
+struct FieldSpec {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    /// Names accepted in place of numbers, starting at `min`.
+    aliases: &'static [&'static str],
+}
+
+const FIELDS: [FieldSpec; 5] = [
+    FieldSpec { name: "minute", min: 0, max: 59, aliases: &[] },
+    FieldSpec { name: "hour", min: 0, max: 23, aliases: &[] },
+    FieldSpec { name: "day-of-month", min: 1, max: 31, aliases: &[] },
+    FieldSpec { name: "month", min: 1, max: 12, aliases: MONTHS },
+    // 7 is accepted as an alias for Sunday, as in most cron implementations.
+    FieldSpec { name: "day-of-week", min: 0, max: 7, aliases: DAYS },
+];
+
+pub fn cron_command() -> Command {
+    Command::new("cron")
+        .usage(r#"oat cron "[minute hour day-of-month month day-of-week]" [--count N]"#)
+        .flag(Flag::new("count", FlagType::Uint).alias("n").description("Number of upcoming run times to show (default: 5)"))
+        .action(|c| exit_on_error(cron_action(c)))
+}
+
+fn expand_macro(expression: &str) -> &str {
+    match expression {
+        "@yearly" | "@annually" => "0 0 1 1 *",
+        "@monthly" => "0 0 1 * *",
+        "@weekly" => "0 0 * * 0",
+        "@daily" | "@midnight" => "0 0 * * *",
+        "@hourly" => "0 * * * *",
+        other => other,
+    }
+}
+
+fn parse_value(value: &str, spec: &FieldSpec, field: &str) -> Result<u32, String> {
+    let lower = value.to_lowercase();
+    if let Some(index) = spec.aliases.iter().position(|alias| *alias == lower) {
+        return Ok(spec.min + index as u32);
+    }
+
+    let number: u32 = value
+        .parse()
+        .map_err(|_| format!("Invalid {} field '{}': '{}' is not a valid value", spec.name, field, value))?;
+    if number < spec.min || number > spec.max {
+        return Err(format!(
+            "Invalid {} field '{}': {} is out of range {}-{}",
+            spec.name, field, number, spec.min, spec.max
+        ));
+    }
+    Ok(number)
+}
+
+/// Expands one cron field into the sorted list of values it matches.
+fn expand_field(field: &str, spec: &FieldSpec) -> Result<Vec<u32>, String> {
+    let mut values = Vec::new();
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| format!("Invalid {} field '{}': bad step '{}'", spec.name, field, step))?;
+                (range, Some(step))
+            }
+            None => (part, None),
+        };
+
+        let (start, end) = if range == "*" {
+            (spec.min, spec.max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start, spec, field)?, parse_value(end, spec, field)?)
+        } else {
+            let value = parse_value(range, spec, field)?;
+            (value, if step.is_some() { spec.max } else { value })
+        };
+
+        if start > end {
+            return Err(format!("Invalid {} field '{}': range {}-{} is reversed", spec.name, field, start, end));
+        }
+        values.extend((start..=end).step_by(step.unwrap_or(1) as usize));
+    }
+
+    if spec.name == "day-of-week" {
+        values = values.into_iter().map(|day| day % 7).collect();
+    }
+    values.sort_unstable();
+    values.dedup();
+    Ok(values)
+}
+
+fn value_name(value: &str, names: &[&str], aliases: &[&str], min: u32) -> String {
+    let lower = value.to_lowercase();
+    let index = match value.parse::<u32>() {
+        Ok(number) => (number - min) as usize % names.len(),
+        Err(_) => aliases.iter().position(|alias| *alias == lower).unwrap_or(0),
+    };
+    names[index].to_string()
+}
+
+/// How a field reads in a description: plain values are wrapped as
+/// `{prefix}1, 5{suffix}`, stepped parts read as "every 10 {unit}".
+struct FieldWords {
+    names: Option<(&'static [&'static str], &'static [&'static str], u32)>,
+    unit: &'static str,
+    prefix: &'static str,
+    suffix: &'static str,
+}
+
+/// Describes a field in words using its original tokens, e.g. "1-5" on the
+/// day-of-week field becomes "on Monday through Friday" and "0-30/10" on the
+/// minute field becomes "every 10 minutes from 0 through 30".
+fn describe_field(field: &str, words: &FieldWords) -> String {
+    let name = |value: &str| match words.names {
+        Some((names, aliases, min)) => value_name(value, names, aliases, min),
+        None => value.to_string(),
+    };
+    let range_text = |range: &str| match range.split_once('-') {
+        Some((start, end)) => format!("{} through {}", name(start), name(end)),
+        None if range == "*" => String::new(),
+        None => name(range),
+    };
+
+    let mut values = Vec::new();
+    let mut steps = Vec::new();
+    for part in field.split(',') {
+        match part.split_once('/') {
+            Some((range, step)) => match range_text(range) {
+                range if range.is_empty() => steps.push(format!("every {} {}", step, words.unit)),
+                range => steps.push(format!("every {} {} from {}", step, words.unit, range)),
+            },
+            None => values.push(range_text(part)),
+        }
+    }
+
+    let mut parts = Vec::new();
+    if !values.is_empty() {
+        parts.push(format!("{}{}{}", words.prefix, values.join(", "), words.suffix));
+    }
+    parts.extend(steps);
+    parts.join(", ")
+}
+
+const MINUTE_WORDS: FieldWords = FieldWords { names: None, unit: "minutes", prefix: "at minute ", suffix: "" };
+const HOUR_WORDS: FieldWords = FieldWords { names: None, unit: "hours", prefix: "during hour ", suffix: "" };
+const DAY_WORDS: FieldWords = FieldWords { names: None, unit: "days", prefix: "on day ", suffix: " of the month" };
+const MONTH_WORDS: FieldWords =
+    FieldWords { names: Some((MONTH_NAMES, MONTHS, 1)), unit: "months", prefix: "in ", suffix: "" };
+const WEEKDAY_WORDS: FieldWords =
+    FieldWords { names: Some((DAY_NAMES, DAYS, 0)), unit: "days of the week", prefix: "on ", suffix: "" };
+
+fn describe(fields: &[&str]) -> String {
+    let (minute, hour, day, month, weekday) = (fields[0], fields[1], fields[2], fields[3], fields[4]);
+    let mut parts = Vec::new();
+
+    let is_number = |field: &str| field.parse::<u32>().is_ok();
+    if is_number(minute) && is_number(hour) {
+        parts.push(format!("at {:0>2}:{:0>2}", hour, minute));
+    } else {
+        parts.push(match minute {
+            "*" => "every minute".to_string(),
+            _ => describe_field(minute, &MINUTE_WORDS),
+        });
+        if hour != "*" {
+            parts.push(describe_field(hour, &HOUR_WORDS));
+        }
+    }
+
+    let day_text = describe_field(day, &DAY_WORDS);
+    let weekday_text = describe_field(weekday, &WEEKDAY_WORDS);
+    if restricts_days(fields) {
+        parts.push(format!("{} or {}", day_text, weekday_text));
+    } else if day != "*" {
+        parts.push(day_text);
+    }
+    if month != "*" {
+        parts.push(describe_field(month, &MONTH_WORDS));
+    }
+    if weekday != "*" && !restricts_days(fields) {
+        parts.push(weekday_text);
+    }
+
+    parts.join(", ")
+}
+
+fn join_values(values: &[u32]) -> String {
+    values.iter().map(u32::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// Whether both day fields are restricted. Like Vixie cron, a field starting
+/// with `*` (including `*/N`) counts as unrestricted.
+fn restricts_days(fields: &[&str]) -> bool {
+    !fields[2].starts_with('*') && !fields[4].starts_with('*')
+}
+
+/// Builds the equivalent expression for the `cron` crate, which expects a
+/// leading seconds field and numbers days of the week from 1 (Sunday).
+fn to_schedule(expanded: &[Vec<u32>]) -> Result<Schedule, String> {
+    let weekdays: Vec<String> = expanded[4].iter().map(|day| DAYS[*day as usize].to_string()).collect();
+    let expression = format!(
+        "0 {} {} {} {} {}",
+        join_values(&expanded[0]),
+        join_values(&expanded[1]),
+        join_values(&expanded[2]),
+        join_values(&expanded[3]),
+        weekdays.join(",")
+    );
+    Schedule::from_str(&expression).map_err(|e| format!("Invalid expression: {}", e))
+}
+
+/// Parses a five-field expression into the schedules it fires on.
+///
+/// The `cron` crate requires both day fields to match, while standard cron
+/// fires when either does if both are restricted. That case becomes two
+/// schedules, one per day field, whose run times are merged.
+fn parse_schedules(expression: &str) -> Result<Vec<Schedule>, String> {
+    let fields: Vec<&str> = expression.split_whitespace().collect();
+    if fields.len() != 5 {
+        return Err(format!(
+            "Expected 5 fields (minute hour day-of-month month day-of-week), found {}",
+            fields.len()
+        ));
+    }
+
+    let expanded = fields
+        .iter()
+        .zip(FIELDS.iter())
+        .map(|(field, spec)| expand_field(field, spec))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !restricts_days(&fields) {
+        return Ok(vec![to_schedule(&expanded)?]);
+    }
+
+    let mut by_day = expanded.clone();
+    by_day[4] = (0..=6).collect();
+    let mut by_weekday = expanded;
+    by_weekday[2] = (1..=31).collect();
+    Ok(vec![to_schedule(&by_day)?, to_schedule(&by_weekday)?])
+}
+
+/// Returns the next `count` run times after `start` across all schedules.
+fn next_runs<Tz: TimeZone>(schedules: &[Schedule], start: &DateTime<Tz>, count: usize) -> Vec<DateTime<Tz>> {
+    let mut times: Vec<DateTime<Tz>> = schedules
+        .iter()
+        .flat_map(|schedule| schedule.after(start).take(count))
+        .collect();
+    times.sort();
+    times.dedup();
+    times.truncate(count);
+    times
+}
+
+fn cron_action(c: &Context) -> Result<(), OatError> {
+    let input = c.args.join(" ");
+    let expression = expand_macro(input.trim());
+    let schedules = parse_schedules(expression).map_err(OatError::Usage)?;
+    let fields: Vec<&str> = expression.split_whitespace().collect();
+    let count = uint_flag(c, "count")?.unwrap_or(5);
+
+    // Fields can each be valid yet never line up, e.g. February 30th.
+    let runs = next_runs(&schedules, &Local::now(), count.max(1));
+    if runs.is_empty() {
+        return Err(OatError::Usage(format!("'{}' never matches a real date", expression)));
+    }
+
+    println!("Expression: {}", expression);
+    println!("Meaning:    {}", describe(&fields));
+    println!();
+    println!("Next runs:");
+    for time in runs.iter().take(count) {
+        println!("  {}", time.format("%Y-%m-%d %H:%M %Z"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn runs(expression: &str, count: usize) -> Vec<String> {
+        let schedules = parse_schedules(expression).unwrap();
+        let start = Utc.with_ymd_and_hms(2026, 10, 17, 0, 0, 0).unwrap();
+        next_runs(&schedules, &start, count)
+            .iter()
+            .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn day_of_month_and_day_of_week_are_ored() {
+        assert_eq!(
+            runs("0 0 1 * 1", 5),
+            ["2026-10-19 00:00", "2026-10-26 00:00", "2026-11-01 00:00", "2026-11-02 00:00", "2026-11-09 00:00"]
+        );
+        assert_eq!(describe(&["0", "0", "1", "*", "1"]), "at 00:00, on day 1 of the month or on Monday");
+    }
+
+    #[test]
+    fn star_step_day_field_does_not_restrict() {
+        assert_eq!(runs("0 12 */10 * 5", 3), ["2026-12-11 12:00", "2027-01-01 12:00", "2027-05-21 12:00"]);
+    }
+
+    #[test]
+    fn single_day_field_is_unchanged() {
+        assert_eq!(runs("30 9 * * 1-5", 2), ["2026-10-19 09:30", "2026-10-20 09:30"]);
+        assert_eq!(runs("0 0 15 * *", 2), ["2026-11-15 00:00", "2026-12-15 00:00"]);
+    }
+
+    #[test]
+    fn describes_fields() {
+        assert_eq!(describe(&["*/5", "*", "*", "*", "*"]), "every 5 minutes");
+        assert_eq!(describe(&["30", "9", "*", "*", "1-5"]), "at 09:30, on Monday through Friday");
+        assert_eq!(
+            describe(&["15,45", "8-17", "*", "jan,jul", "*"]),
+            "at minute 15, 45, during hour 8 through 17, in January, July"
+        );
+    }
+
+    #[test]
+    fn describes_stepped_ranges() {
+        assert_eq!(
+            describe(&["0-30/10", "9-17/4", "*", "*", "*"]),
+            "every 10 minutes from 0 through 30, every 4 hours from 9 through 17"
+        );
+        assert_eq!(describe(&["5,20-40/10", "*/2", "*", "*", "*"]), "at minute 5, every 10 minutes from 20 through 40, every 2 hours");
+        assert_eq!(describe(&["0", "0", "*/10", "*", "*"]), "at 00:00, every 10 days");
+    }
+
+    #[test]
+    fn impossible_dates_have_no_runs() {
+        assert!(runs("0 0 31 2 *", 1).is_empty());
+        assert!(runs("0 0 30 2 *", 1).is_empty());
+        assert_eq!(runs("0 0 29 2 *", 1), ["2028-02-29 00:00"]);
+    }
+
+    #[test]
+    fn rejects_malformed_fields() {
+        assert_eq!(parse_schedules("61 * * * *").unwrap_err(), "Invalid minute field '61': 61 is out of range 0-59");
+        assert!(parse_schedules("* * *").unwrap_err().starts_with("Expected 5 fields"));
+    }
+}
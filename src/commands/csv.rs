@@ -0,0 +1,67 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::delimiter;
+use crate::util::input::read_file_or_stdin;
+use csv::ReaderBuilder;
+use seahorse::{Command, Context, Flag, FlagType};
+use serde_json::{Map, Value};
+
+pub fn csv_command() -> Command {
+    Command::new("csv")
+        .usage("oat csv [to-json]")
+        .command(to_json_command())
+}
+
+fn to_json_command() -> Command {
+    Command::new("to-json")
+        .usage("oat csv to-json [file] [--delimiter C] [--no-header] [--compact]")
+        .flag(Flag::new("delimiter", FlagType::String).alias("d").description("Field delimiter, or 'tab' (default: ,)"))
+        .flag(Flag::new("no-header", FlagType::Bool).description("Treat the first row as data and output arrays instead of objects"))
+        .flag(Flag::new("compact", FlagType::Bool).alias("c").description("Print the JSON on a single line"))
+        .action(|c| exit_on_error(to_json_action(c)))
+}
+
+fn to_json_action(c: &Context) -> Result<(), OatError> {
+    let input = read_file_or_stdin(c.args.first())?;
+    let has_header = !c.bool_flag("no-header");
+
+    let mut reader = ReaderBuilder::new()
+        .delimiter(delimiter(c)?)
+        .has_headers(has_header)
+        .flexible(true)
+        .from_reader(input.as_bytes());
+
+    let headers: Vec<String> = if has_header {
+        let headers = reader.headers().map_err(|e| OatError::Failed(format!("Invalid CSV: {}", e)))?;
+        headers.iter().map(String::from).collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| OatError::Failed(format!("Invalid CSV: {}", e)))?;
+        let fields = record.iter().map(|field| Value::String(field.to_string()));
+
+        if has_header {
+            let mut object = Map::new();
+            for (i, field) in fields.enumerate() {
+                // Rows longer than the header get positional column names.
+                let key = headers.get(i).cloned().unwrap_or_else(|| format!("column{}", i + 1));
+                object.insert(key, field);
+            }
+            rows.push(Value::Object(object));
+        } else {
+            rows.push(Value::Array(fields.collect()));
+        }
+    }
+
+    let rows = Value::Array(rows);
+    let json = if c.bool_flag("compact") {
+        serde_json::to_string(&rows)
+    } else {
+        serde_json::to_string_pretty(&rows)
+    };
+    println!("{}", json.map_err(|e| OatError::Failed(format!("Failed to encode JSON: {}", e)))?);
+
+    Ok(())
+}
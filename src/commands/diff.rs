@@ -0,0 +1,78 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::uint_flag;
+use crate::util::input::read_file;
+use crate::util::output::color_allowed;
+use seahorse::{Command, Context, Flag, FlagType};
+use similar::{ChangeTag, TextDiff};
+use std::process;
+
+pub fn diff_command() -> Command {
+    Command::new("diff")
+        .usage(r#"oat diff [file-a] [file-b] | oat diff --text "[a]" "[b]" [--context N] [--color]"#)
+        .flag(Flag::new("text", FlagType::Bool).alias("t").description("Compare the two arguments as strings instead of file paths"))
+        .flag(Flag::new("context", FlagType::Uint).alias("c").description("Number of context lines around changes (default: 3)"))
+        .flag(Flag::new("color", FlagType::Bool).description("Color added and removed lines"))
+        .action(|c| match diff_action(c) {
+            // Like diff(1), exit with status 1 when the inputs differ so the
+            // command can be used in scripts and hooks.
+            Ok(true) => process::exit(1),
+            result => exit_on_error(result.map(|_| ())),
+        })
+}
+
+fn with_trailing_newline(text: &str) -> String {
+    if text.ends_with('\n') {
+        text.to_string()
+    } else {
+        format!("{}\n", text)
+    }
+}
+
+fn print_colored<'a>(diff: &'a TextDiff<'a, 'a, 'a, str>, context: usize, old_label: &str, new_label: &str) {
+    println!("\x1b[1m--- {}\x1b[0m", old_label);
+    println!("\x1b[1m+++ {}\x1b[0m", new_label);
+
+    for hunk in diff.unified_diff().context_radius(context).iter_hunks() {
+        println!("\x1b[36m{}\x1b[0m", hunk.header());
+        for change in hunk.iter_changes() {
+            let (sign, color) = match change.tag() {
+                ChangeTag::Delete => ("-", "\x1b[31m"),
+                ChangeTag::Insert => ("+", "\x1b[32m"),
+                ChangeTag::Equal => (" ", ""),
+            };
+            let line = change.value();
+            println!("{}{}{}\x1b[0m", color, sign, line.strip_suffix('\n').unwrap_or(line));
+            if change.missing_newline() {
+                println!("\\ No newline at end of file");
+            }
+        }
+    }
+}
+
+/// Prints a unified diff of the inputs and returns whether they differ.
+fn diff_action(c: &Context) -> Result<bool, OatError> {
+    let (a, b) = match c.args.as_slice() {
+        [a, b] => (a, b),
+        _ => return Err(OatError::Usage("Please provide exactly two files or strings to compare".to_string())),
+    };
+    let context = uint_flag(c, "context")?.unwrap_or(3);
+
+    let (old, new, old_label, new_label) = if c.bool_flag("text") {
+        (with_trailing_newline(a), with_trailing_newline(b), "a".to_string(), "b".to_string())
+    } else {
+        (read_file(a)?, read_file(b)?, a.clone(), b.clone())
+    };
+
+    if old == new {
+        return Ok(false);
+    }
+
+    let diff = TextDiff::from_lines(&old, &new);
+    if c.bool_flag("color") && color_allowed() {
+        print_colored(&diff, context, &old_label, &new_label);
+    } else {
+        print!("{}", diff.unified_diff().context_radius(context).header(&old_label, &new_label));
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,87 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::string_flag;
+use crate::util::block_on;
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig, ResolverOpts};
+use hickory_resolver::error::ResolveErrorKind;
+use hickory_resolver::proto::rr::RecordType;
+use hickory_resolver::TokioAsyncResolver;
+use seahorse::{Command, Context, Flag, FlagType};
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+
+pub fn dns_command() -> Command {
+    Command::new("dns")
+        .usage("oat dns [hostname] [--type A|AAAA|MX|TXT|CNAME] [--server 8.8.8.8]")
+        .flag(Flag::new("type", FlagType::String).alias("t").description("Record type to look up (default: A)"))
+        .flag(Flag::new("server", FlagType::String).alias("s").description("Query this resolver instead of the system one (ip or ip:port)"))
+        .action(|c| exit_on_error(block_on(dns_action(c))))
+}
+
+fn parse_record_type(value: &str) -> Result<RecordType, OatError> {
+    let record_type = RecordType::from_str(&value.to_uppercase())
+        .map_err(|_| OatError::Usage(format!("Unknown record type '{}'", value)))?;
+
+    match record_type {
+        RecordType::A | RecordType::AAAA | RecordType::MX | RecordType::TXT | RecordType::CNAME => Ok(record_type),
+        _ => Err(OatError::Usage(format!(
+            "Unsupported record type '{}'. Use A, AAAA, MX, TXT or CNAME",
+            value
+        ))),
+    }
+}
+
+fn parse_server(value: &str) -> Result<SocketAddr, OatError> {
+    if let Ok(addr) = value.parse::<SocketAddr>() {
+        return Ok(addr);
+    }
+
+    value
+        .parse::<IpAddr>()
+        .map(|ip| SocketAddr::new(ip, 53))
+        .map_err(|_| OatError::Usage(format!("Invalid server address '{}'", value)))
+}
+
+fn build_resolver(server: Option<SocketAddr>) -> Result<TokioAsyncResolver, OatError> {
+    match server {
+        Some(addr) => {
+            let name_servers = NameServerConfigGroup::from_ips_clear(&[addr.ip()], addr.port(), true);
+            let config = ResolverConfig::from_parts(None, vec![], name_servers);
+            Ok(TokioAsyncResolver::tokio(config, ResolverOpts::default()))
+        }
+        None => TokioAsyncResolver::tokio_from_system_conf()
+            .map_err(|e| OatError::Failed(format!("Failed to read system resolver config: {}", e))),
+    }
+}
+
+async fn dns_action(c: &Context) -> Result<(), OatError> {
+    let host = c
+        .args
+        .first()
+        .ok_or_else(|| OatError::Usage("Please provide a hostname".to_string()))?;
+
+    let record_type = match string_flag(c, "type")? {
+        Some(value) => parse_record_type(&value)?,
+        None => RecordType::A,
+    };
+
+    let server = match string_flag(c, "server")? {
+        Some(value) => Some(parse_server(&value)?),
+        None => None,
+    };
+
+    let resolver = build_resolver(server)?;
+    let lookup = resolver.lookup(host.as_str(), record_type).await.map_err(|e| match e.kind() {
+        ResolveErrorKind::NoRecordsFound { .. } => {
+            OatError::Failed(format!("No {} records found for {}", record_type, host))
+        }
+        _ => OatError::Network(format!("DNS lookup failed: {}", e)),
+    })?;
+
+    for record in lookup.record_iter().filter(|r| r.record_type() == record_type) {
+        if let Some(data) = record.data() {
+            println!("{}\t{}\t{}\t{}", record.name(), record.ttl(), record.record_type(), data);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,235 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::{string_flag, uint_flag};
+use crate::util::block_on;
+use crate::util::output::info;
+use seahorse::{Command, Context, Flag, FlagType};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+
+pub fn generate_command() -> Command {
+    Command::new("generate")
+        .usage("oat generate [subcommand]")
+        .command(dalle_command())
+}
+
+fn dalle_command() -> Command {
+    Command::new("dalle")
+        .usage(r#"oat generate dalle "[prompt]" [--count N] [--size WxH] [--quality standard|hd] [--model dall-e-2|dall-e-3] [--save out.png]"#)
+        .flag(Flag::new("count", FlagType::Uint).description("Number of images to generate (default: 1)"))
+        .flag(Flag::new("size", FlagType::String).description("Image size, e.g. 1024x1024"))
+        .flag(Flag::new("quality", FlagType::String).description("Image quality: standard or hd (dall-e-3 only)"))
+        .flag(Flag::new("model", FlagType::String).description("Model to use: dall-e-2 or dall-e-3 (default: dall-e-3)"))
+        .flag(Flag::new("save", FlagType::String).description("Download the generated image(s) to this path"))
+        .action(|c| exit_on_error(dalle(c)))
+}
+
+fn dalle(c: &Context) -> Result<(), OatError> {
+    let save = string_flag(c, "save")?;
+    let request_body = dalle_request(c)?;
+    block_on(async { dalle_action(request_body, save).await.map_err(OatError::from) })
+}
+
+#[derive(Debug)]
+pub enum GenerateError {
+    MissingApiKey,
+    InvalidOption(String),
+    RequestError(reqwest::Error),
+    ApiError(StatusCode),
+    NoImageData,
+    DownloadError(StatusCode),
+    SaveError(PathBuf, std::io::Error),
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerateError::MissingApiKey => write!(f, "Set OPENAI_API_KEY to use image generation"),
+            GenerateError::InvalidOption(msg) => write!(f, "{}", msg),
+            GenerateError::RequestError(e) => write!(f, "Request failed: {}", e),
+            GenerateError::ApiError(status) => write!(f, "Failed to generate image: {}", status),
+            GenerateError::NoImageData => write!(f, "No image data found in the response"),
+            GenerateError::DownloadError(status) => write!(f, "Failed to download image: {}", status),
+            GenerateError::SaveError(path, e) => write!(f, "Failed to save image to {}: {}", path.display(), e),
+        }
+    }
+}
+
+impl From<GenerateError> for OatError {
+    fn from(error: GenerateError) -> Self {
+        let message = error.to_string();
+        match error {
+            GenerateError::MissingApiKey | GenerateError::InvalidOption(_) => OatError::Usage(message),
+            GenerateError::RequestError(_)
+            | GenerateError::ApiError(_)
+            | GenerateError::NoImageData
+            | GenerateError::DownloadError(_) => OatError::Network(message),
+            GenerateError::SaveError(_, _) => OatError::Io(message),
+        }
+    }
+}
+
+impl From<reqwest::Error> for GenerateError {
+    fn from(error: reqwest::Error) -> Self {
+        GenerateError::RequestError(error)
+    }
+}
+
+#[derive(Serialize)]
+struct DalleRequest {
+    model: String,
+    prompt: String,
+    n: u32,
+    size: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DalleResponse {
+    data: Vec<ImageData>,
+}
+
+#[derive(Deserialize)]
+struct ImageData {
+    url: String,
+}
+
+/// Converts a flag lookup error into a [`GenerateError`].
+fn flag<T>(result: Result<T, OatError>) -> Result<T, GenerateError> {
+    result.map_err(|e| GenerateError::InvalidOption(e.to_string()))
+}
+
+fn allowed_sizes(model: &str) -> &'static [&'static str] {
+    match model {
+        "dall-e-2" => &["256x256", "512x512", "1024x1024"],
+        _ => &["1024x1024", "1792x1024", "1024x1792"],
+    }
+}
+
+fn dalle_request(c: &Context) -> Result<DalleRequest, GenerateError> {
+    let prompt: String = c.args.join(" ");
+    if prompt.trim().is_empty() {
+        return Err(GenerateError::InvalidOption("Please provide a prompt".to_string()));
+    }
+
+    let model = flag(string_flag(c, "model"))?.unwrap_or_else(|| "dall-e-3".to_string());
+    if model != "dall-e-2" && model != "dall-e-3" {
+        return Err(GenerateError::InvalidOption(format!(
+            "Unknown model '{}'. Use dall-e-2 or dall-e-3",
+            model
+        )));
+    }
+
+    let size = flag(string_flag(c, "size"))?.unwrap_or_else(|| "1024x1024".to_string());
+    let sizes = allowed_sizes(&model);
+    if !sizes.contains(&size.as_str()) {
+        return Err(GenerateError::InvalidOption(format!(
+            "Size '{}' is not supported by {}. Use one of: {}",
+            size,
+            model,
+            sizes.join(", ")
+        )));
+    }
+
+    let count = flag(uint_flag(c, "count"))?.unwrap_or(1);
+    if model == "dall-e-3" && count != 1 {
+        return Err(GenerateError::InvalidOption(
+            "dall-e-3 only supports generating 1 image per request; use --model dall-e-2 for more".to_string(),
+        ));
+    }
+    if count == 0 || count > 10 {
+        return Err(GenerateError::InvalidOption("--count must be between 1 and 10".to_string()));
+    }
+
+    let quality = flag(string_flag(c, "quality"))?;
+    if let Some(quality) = &quality {
+        if model != "dall-e-3" {
+            return Err(GenerateError::InvalidOption("--quality is only supported by dall-e-3".to_string()));
+        }
+        if quality != "standard" && quality != "hd" {
+            return Err(GenerateError::InvalidOption(format!(
+                "Unknown quality '{}'. Use standard or hd",
+                quality
+            )));
+        }
+    }
+
+    Ok(DalleRequest {
+        model,
+        prompt,
+        n: count as u32,
+        size,
+        quality,
+    })
+}
+
+/// Returns the path for the image at `index`, numbering it (`out-1.png`,
+/// `out-2.png`, ...) when more than one image is saved.
+fn numbered_path(path: &Path, index: usize, total: usize) -> PathBuf {
+    if total <= 1 {
+        return path.to_path_buf();
+    }
+
+    let stem = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(ext) => format!("{}-{}.{}", stem, index + 1, ext.to_string_lossy()),
+        None => format!("{}-{}", stem, index + 1),
+    };
+    path.with_file_name(file_name)
+}
+
+async fn download_image(client: &Client, url: &str, path: &Path) -> Result<(), GenerateError> {
+    let response = client.get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(GenerateError::DownloadError(response.status()));
+    }
+
+    let bytes = response.bytes().await?;
+    fs::write(path, &bytes).map_err(|e| GenerateError::SaveError(path.to_path_buf(), e))
+}
+
+async fn dalle_action(request_body: DalleRequest, save: Option<String>) -> Result<(), GenerateError> {
+    let api_key = env::var("OPENAI_API_KEY").map_err(|_| GenerateError::MissingApiKey)?;
+
+    // OPENAI_BASE_URL points the request at a proxy or a local mock server.
+    let base_url = env::var("OPENAI_BASE_URL").unwrap_or_else(|_| "https://api.openai.com/v1".to_string());
+
+    let client = Client::new();
+
+    let response = client
+        .post(format!("{}/images/generations", base_url.trim_end_matches('/')))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", api_key))
+        .json(&request_body)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(GenerateError::ApiError(response.status()));
+    }
+
+    let dalle_response: DalleResponse = response.json().await?;
+    if dalle_response.data.is_empty() {
+        return Err(GenerateError::NoImageData);
+    }
+
+    let total = dalle_response.data.len();
+    for (index, image_data) in dalle_response.data.iter().enumerate() {
+        match &save {
+            Some(save) => {
+                // Print the URL first so it isn't lost if the download fails.
+                info(&image_data.url);
+                let path = numbered_path(Path::new(save), index, total);
+                download_image(&client, &image_data.url, &path).await?;
+                info(&format!("Saved image to {}", path.display()));
+            }
+            None => println!("{}", image_data.url),
+        }
+    }
+
+    Ok(())
+}
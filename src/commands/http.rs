@@ -0,0 +1,121 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::{string_flag, take_repeated, uint_flag};
+use crate::util::block_on;
+use crate::util::output::info;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use reqwest::{Client, Method};
+use seahorse::{Command, Context, Flag, FlagType};
+use std::fs;
+use std::time::Duration;
+
+pub fn http_command() -> Command {
+    Command::new("http")
+        .usage("oat http [METHOD] [url] [--header 'Name: value'] [--data body|--json body] [--output file] [-v]")
+        .flag(Flag::new("header", FlagType::String).alias("H").description("Add a request header, can be repeated"))
+        .flag(Flag::new("data", FlagType::String).alias("d").description("Send this request body (@file reads it from a file)"))
+        .flag(Flag::new("json", FlagType::String).description("Send this JSON request body with a JSON content type"))
+        .flag(Flag::new("output", FlagType::String).alias("o").description("Write the response body to a file"))
+        .flag(Flag::new("timeout", FlagType::Uint).description("Request timeout in seconds (default: 30)"))
+        .flag(Flag::new("verbose", FlagType::Bool).alias("v").description("Print the response headers"))
+        .action(|c| exit_on_error(block_on(http_action(c))))
+}
+
+fn parse_header(header: &str) -> Result<(HeaderName, HeaderValue), OatError> {
+    let invalid = || OatError::Usage(format!("Invalid header '{}'. Use 'Name: value'", header));
+    let (name, value) = header.split_once(':').ok_or_else(invalid)?;
+
+    let name = HeaderName::from_bytes(name.trim().as_bytes()).map_err(|_| invalid())?;
+    let value = HeaderValue::from_str(value.trim()).map_err(|_| invalid())?;
+    Ok((name, value))
+}
+
+fn read_body(data: &str) -> Result<Vec<u8>, OatError> {
+    match data.strip_prefix('@') {
+        Some(path) => fs::read(path).map_err(|e| OatError::Io(format!("Failed to read {}: {}", path, e))),
+        None => Ok(data.as_bytes().to_vec()),
+    }
+}
+
+async fn http_action(c: &Context) -> Result<(), OatError> {
+    let (extra_headers, args) = take_repeated(&c.args, &["--header", "-H"]);
+    let (method, url) = match args.as_slice() {
+        [method, url] => (method, url),
+        [url] => (&"GET".to_string(), url),
+        _ => return Err(OatError::Usage("Usage: oat http [METHOD] [url]".to_string())),
+    };
+    let method = Method::from_bytes(method.to_uppercase().as_bytes())
+        .map_err(|_| OatError::Usage(format!("Invalid method '{}'", method)))?;
+
+    let mut headers = HeaderMap::new();
+    for header in string_flag(c, "header")?.into_iter().chain(extra_headers) {
+        let (name, value) = parse_header(&header)?;
+        headers.append(name, value);
+    }
+
+    let timeout = uint_flag(c, "timeout")?.unwrap_or(30) as u64;
+    let client = Client::builder()
+        .timeout(Duration::from_secs(timeout))
+        .build()
+        .map_err(|e| OatError::Failed(format!("Failed to build HTTP client: {}", e)))?;
+
+    let mut request = client.request(method, url.as_str()).headers(headers);
+    match (string_flag(c, "json")?, string_flag(c, "data")?) {
+        (Some(_), Some(_)) => {
+            return Err(OatError::Usage("--json and --data are mutually exclusive".to_string()));
+        }
+        (Some(json), None) => {
+            let body: serde_json::Value =
+                serde_json::from_str(&json).map_err(|e| OatError::Usage(format!("Invalid --json body: {}", e)))?;
+            request = request.json(&body);
+        }
+        (None, Some(data)) => request = request.body(read_body(&data)?),
+        (None, None) => {}
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| OatError::Network(format!("Request failed: {}", e)))?;
+
+    let status = response.status();
+    info(&format!("{:?} {}", response.version(), status));
+    if c.bool_flag("verbose") {
+        for (name, value) in response.headers() {
+            println!("{}: {}", name, value.to_str().unwrap_or("<binary>"));
+        }
+    }
+    info("");
+
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("json"));
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| OatError::Network(format!("Failed to read response body: {}", e)))?;
+
+    match string_flag(c, "output")? {
+        Some(path) => {
+            fs::write(&path, &body).map_err(|e| OatError::Io(format!("Failed to write {}: {}", path, e)))?;
+            info(&format!("Saved {} bytes to {}", body.len(), path));
+        }
+        None => {
+            let pretty = if is_json {
+                serde_json::from_slice::<serde_json::Value>(&body)
+                    .ok()
+                    .and_then(|value| serde_json::to_string_pretty(&value).ok())
+            } else {
+                None
+            };
+            println!("{}", pretty.unwrap_or_else(|| String::from_utf8_lossy(&body).to_string()));
+        }
+    }
+
+    if status.is_client_error() || status.is_server_error() {
+        return Err(OatError::Network(format!("Request failed with status {}", status)));
+    }
+
+    Ok(())
+}
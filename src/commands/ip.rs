@@ -0,0 +1,152 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::block_on;
+use hickory_resolver::system_conf::read_system_conf;
+use hickory_resolver::TokioAsyncResolver;
+use ipnet::IpNet;
+use seahorse::{Command, Context};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+pub fn ip_command() -> Command {
+    Command::new("ip")
+        .usage("oat ip [address|cidr]  (no argument lists this machine's addresses)")
+        .action(|c| exit_on_error(block_on(ip_action(c))))
+}
+
+fn classify_v4(ip: Ipv4Addr) -> &'static str {
+    let octets = ip.octets();
+    if ip.is_loopback() {
+        "loopback"
+    } else if ip.is_unspecified() {
+        "unspecified"
+    } else if ip.is_link_local() {
+        "link-local"
+    } else if ip.is_private() {
+        "private"
+    } else if octets[0] == 100 && (octets[1] & 0xc0) == 64 {
+        "shared (carrier-grade NAT)"
+    } else if ip.is_multicast() {
+        "multicast"
+    } else if ip.is_broadcast() {
+        "broadcast"
+    } else if ip.is_documentation() {
+        "documentation"
+    } else {
+        "public"
+    }
+}
+
+fn classify_v6(ip: Ipv6Addr) -> &'static str {
+    let first = ip.segments()[0];
+    if ip.is_loopback() {
+        "loopback"
+    } else if ip.is_unspecified() {
+        "unspecified"
+    } else if ip.is_multicast() {
+        "multicast"
+    } else if (first & 0xffc0) == 0xfe80 {
+        "link-local"
+    } else if (first & 0xfe00) == 0xfc00 {
+        "private (unique local)"
+    } else if let Some(v4) = ip.to_ipv4_mapped() {
+        classify_v4(v4)
+    } else if first == 0x2001 && ip.segments()[1] == 0x0db8 {
+        "documentation"
+    } else {
+        "public"
+    }
+}
+
+fn classify(ip: IpAddr) -> &'static str {
+    match ip {
+        IpAddr::V4(v4) => classify_v4(v4),
+        IpAddr::V6(v6) => classify_v6(v6),
+    }
+}
+
+/// Looks up the PTR record for `ip`, returning `None` when there is none or
+/// the resolver can't be reached.
+async fn reverse_dns(ip: IpAddr) -> Option<String> {
+    let (config, mut options) = read_system_conf().ok()?;
+    options.timeout = Duration::from_secs(2);
+    options.attempts = 1;
+
+    let resolver = TokioAsyncResolver::tokio(config, options);
+    let lookup = resolver.reverse_lookup(ip).await.ok()?;
+    let names: Vec<String> = lookup.iter().map(|name| name.to_string()).collect();
+    if names.is_empty() {
+        None
+    } else {
+        Some(names.join(", "))
+    }
+}
+
+fn print_network(net: &IpNet) {
+    let trunc = net.trunc();
+    println!("Network:   {}", trunc);
+    println!("Netmask:   {}", net.netmask());
+
+    match net {
+        IpNet::V4(v4) => {
+            println!("Broadcast: {}", v4.broadcast());
+            let size = 1u64 << (32 - v4.prefix_len());
+            let (hosts, first, last) = match v4.prefix_len() {
+                32 => (1, u32::from(v4.network()), u32::from(v4.network())),
+                31 => (2, u32::from(v4.network()), u32::from(v4.broadcast())),
+                _ => (size - 2, u32::from(v4.network()) + 1, u32::from(v4.broadcast()) - 1),
+            };
+            println!("Hosts:     {} ({} - {})", hosts, Ipv4Addr::from(first), Ipv4Addr::from(last));
+        }
+        IpNet::V6(v6) => {
+            let bits = 128 - v6.prefix_len() as u32;
+            let hosts = if bits == 128 {
+                "2^128".to_string()
+            } else {
+                (1u128 << bits).to_string()
+            };
+            println!("Hosts:     {} ({} - {})", hosts, v6.network(), v6.broadcast());
+        }
+    }
+}
+
+fn list_local_addresses() -> Result<(), OatError> {
+    let interfaces = if_addrs::get_if_addrs()
+        .map_err(|e| OatError::Io(format!("Failed to list network interfaces: {}", e)))?;
+
+    for interface in interfaces {
+        let ip = interface.ip();
+        println!("{:<12} {:<40} {}", interface.name, ip, classify(ip));
+    }
+
+    Ok(())
+}
+
+async fn ip_action(c: &Context) -> Result<(), OatError> {
+    let input = match c.args.first() {
+        Some(input) => input,
+        None => return list_local_addresses(),
+    };
+
+    let (ip, net) = if input.contains('/') {
+        let net: IpNet = input
+            .parse()
+            .map_err(|_| OatError::Usage(format!("Invalid CIDR '{}'", input)))?;
+        (net.addr(), Some(net))
+    } else {
+        let ip: IpAddr = input
+            .parse()
+            .map_err(|_| OatError::Usage(format!("Invalid IP address '{}'", input)))?;
+        (ip, None)
+    };
+
+    println!("Address:   {}", ip);
+    println!("Version:   {}", if ip.is_ipv4() { "IPv4" } else { "IPv6" });
+    println!("Scope:     {}", classify(ip));
+    println!("Reverse:   {}", reverse_dns(ip).await.unwrap_or_else(|| "none".to_string()));
+
+    if let Some(net) = net {
+        print_network(&net);
+    }
+
+    Ok(())
+}
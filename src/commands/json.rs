@@ -0,0 +1,78 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::delimiter;
+use crate::util::input::read_file_or_stdin;
+use csv::WriterBuilder;
+use seahorse::{Command, Context, Flag, FlagType};
+use serde_json::Value;
+use std::io;
+
+pub fn json_command() -> Command {
+    Command::new("json")
+        .usage("oat json [to-csv]")
+        .command(to_csv_command())
+}
+
+fn to_csv_command() -> Command {
+    Command::new("to-csv")
+        .usage("oat json to-csv [file] [--delimiter C] [--no-header]")
+        .flag(Flag::new("delimiter", FlagType::String).alias("d").description("Field delimiter, or 'tab' (default: ,)"))
+        .flag(Flag::new("no-header", FlagType::Bool).description("Do not write a header row"))
+        .action(|c| exit_on_error(to_csv_action(c)))
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        // Nested arrays and objects are kept as JSON text.
+        other => other.to_string(),
+    }
+}
+
+/// Returns every key that appears in any record, in first-seen order.
+fn header(records: &[Value]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for record in records {
+        if let Value::Object(object) = record {
+            for key in object.keys() {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+    }
+    keys
+}
+
+fn to_csv_action(c: &Context) -> Result<(), OatError> {
+    let input = read_file_or_stdin(c.args.first())?;
+    let json: Value = serde_json::from_str(&input).map_err(|e| OatError::Failed(format!("Invalid JSON: {}", e)))?;
+    let records = match json {
+        Value::Array(records) => records,
+        object @ Value::Object(_) => vec![object],
+        _ => return Err(OatError::Failed("Expected a JSON array of records or a single object".to_string())),
+    };
+
+    let keys = header(&records);
+    let mut writer = WriterBuilder::new()
+        .delimiter(delimiter(c)?)
+        .flexible(true)
+        .from_writer(io::stdout());
+    let write_error = |e: csv::Error| OatError::Io(format!("Failed to write CSV: {}", e));
+
+    if !keys.is_empty() && !c.bool_flag("no-header") {
+        writer.write_record(&keys).map_err(write_error)?;
+    }
+
+    for record in &records {
+        let row: Vec<String> = match record {
+            Value::Object(object) => keys.iter().map(|key| object.get(key).map(cell).unwrap_or_default()).collect(),
+            Value::Array(fields) => fields.iter().map(cell).collect(),
+            other => vec![cell(other)],
+        };
+        writer.write_record(&row).map_err(write_error)?;
+    }
+    writer.flush().map_err(|e| OatError::Io(format!("Failed to write CSV: {}", e)))?;
+
+    Ok(())
+}
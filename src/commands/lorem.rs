@@ -0,0 +1,98 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::uint_flag;
+use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use seahorse::{Command, Context, Flag, FlagType};
+
+const OPENING: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit";
+
+const WORDS: &[&str] = &[
+    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit", "sed", "do",
+    "eiusmod", "tempor", "incididunt", "ut", "labore", "et", "dolore", "magna", "aliqua", "enim",
+    "ad", "minim", "veniam", "quis", "nostrud", "exercitation", "ullamco", "laboris", "nisi",
+    "aliquip", "ex", "ea", "commodo", "consequat", "duis", "aute", "irure", "in", "reprehenderit",
+    "voluptate", "velit", "esse", "cillum", "eu", "fugiat", "nulla", "pariatur", "excepteur",
+    "sint", "occaecat", "cupidatat", "non", "proident", "sunt", "culpa", "qui", "officia",
+    "deserunt", "mollit", "anim", "id", "est", "laborum", "integer", "vitae", "justo", "eget",
+    "fermentum", "iaculis", "pellentesque", "habitant", "morbi", "tristique", "senectus",
+    "netus", "malesuada", "fames", "ac", "turpis", "egestas", "mauris", "nunc", "congue", "nisl",
+    "purus", "viverra", "accumsan", "lacus", "vel", "facilisis", "volutpat", "blandit", "cursus",
+    "risus", "ultrices", "sagittis", "orci", "scelerisque", "varius", "feugiat", "pretium",
+];
+
+pub fn lorem_command() -> Command {
+    Command::new("lorem")
+        .usage("oat lorem [--paragraphs N | --sentences N | --words N] [--start-with-lorem]")
+        .flag(Flag::new("paragraphs", FlagType::Uint).alias("p").description("Number of paragraphs to generate (default: 1)"))
+        .flag(Flag::new("sentences", FlagType::Uint).alias("s").description("Number of sentences to generate"))
+        .flag(Flag::new("words", FlagType::Uint).alias("w").description("Number of words to generate"))
+        .flag(Flag::new("start-with-lorem", FlagType::Bool).alias("l").description("Begin with the classic \"Lorem ipsum dolor sit amet...\" opening"))
+        .action(|c| exit_on_error(lorem_action(c)))
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn random_words(count: usize) -> Vec<&'static str> {
+    (0..count).map(|_| *WORDS.choose(&mut OsRng).unwrap()).collect()
+}
+
+fn sentence() -> String {
+    let mut words: Vec<String> = random_words(OsRng.gen_range(6..=14)).into_iter().map(String::from).collect();
+    // Longer sentences get a comma somewhere in the middle.
+    if words.len() > 9 {
+        let index = OsRng.gen_range(3..words.len() - 3);
+        words[index].push(',');
+    }
+    format!("{}.", capitalize(&words.join(" ")))
+}
+
+fn sentences(count: usize, start_with_lorem: bool) -> Vec<String> {
+    (0..count)
+        .map(|i| if i == 0 && start_with_lorem { format!("{}.", OPENING) } else { sentence() })
+        .collect()
+}
+
+fn words(count: usize, start_with_lorem: bool) -> String {
+    let mut words: Vec<&str> = Vec::with_capacity(count);
+    if start_with_lorem {
+        words.extend(OPENING.split(' ').take(count));
+    }
+    words.extend(random_words(count - words.len()));
+    format!("{}.", capitalize(words.join(" ").trim_end_matches(',')))
+}
+
+fn lorem_action(c: &Context) -> Result<(), OatError> {
+    let start_with_lorem = c.bool_flag("start-with-lorem");
+    let paragraphs = uint_flag(c, "paragraphs")?;
+    let sentence_count = uint_flag(c, "sentences")?;
+    let word_count = uint_flag(c, "words")?;
+
+    let modes = [paragraphs, sentence_count, word_count].iter().filter(|mode| mode.is_some()).count();
+    if modes > 1 {
+        return Err(OatError::Usage("Use only one of --paragraphs, --sentences or --words".to_string()));
+    }
+
+    if let Some(count) = word_count {
+        if count > 0 {
+            println!("{}", words(count, start_with_lorem));
+        }
+    } else if let Some(count) = sentence_count {
+        if count > 0 {
+            println!("{}", sentences(count, start_with_lorem).join(" "));
+        }
+    } else {
+        let paragraphs: Vec<String> = (0..paragraphs.unwrap_or(1))
+            .map(|i| sentences(OsRng.gen_range(4..=7), i == 0 && start_with_lorem).join(" "))
+            .collect();
+        println!("{}", paragraphs.join("\n\n"));
+    }
+
+    Ok(())
+}
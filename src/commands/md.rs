@@ -0,0 +1,120 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::string_flag;
+use crate::util::input::read_file_or_stdin;
+use crate::util::output::info;
+use pulldown_cmark::{html, Event, Options, Parser, Tag, TagEnd};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use seahorse::{Command, Context, Flag, FlagType};
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+pub fn md_command() -> Command {
+    Command::new("md")
+        .usage("oat md [file] [--output path] [--standalone] [--open]")
+        .flag(Flag::new("output", FlagType::String).alias("o").description("Write the HTML to a file instead of stdout"))
+        .flag(Flag::new("standalone", FlagType::Bool).alias("s").description("Wrap the HTML in a complete document with a <title>"))
+        .flag(Flag::new("open", FlagType::Bool).description("Open the rendered document in the default browser"))
+        .action(|c| exit_on_error(md_action(c)))
+}
+
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS | Options::ENABLE_FOOTNOTES
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Uses the text of the first heading as the document title.
+fn first_heading(markdown: &str) -> Option<String> {
+    let mut title = String::new();
+    let mut in_heading = false;
+
+    for event in Parser::new_ext(markdown, options()) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => in_heading = true,
+            Event::End(TagEnd::Heading(_)) if in_heading => break,
+            Event::Text(text) | Event::Code(text) if in_heading => title.push_str(&text),
+            _ => {}
+        }
+    }
+
+    let title = title.trim().to_string();
+    (!title.is_empty()).then_some(title)
+}
+
+fn standalone(body: &str, title: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape_html(title),
+        body
+    )
+}
+
+/// Creates a new preview file in the temp dir. The name has a random
+/// suffix and is opened with `create_new`, so another user can't pre-create
+/// it or swap in a symlink, and two previews of the same file don't collide.
+fn create_preview_file(source: Option<&String>) -> Result<(File, PathBuf), OatError> {
+    let name = source
+        .and_then(|path| Path::new(path).file_stem())
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "stdin".to_string());
+
+    loop {
+        let path = env::temp_dir().join(format!("oat-md-{}-{:016x}.html", name, OsRng.next_u64()));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((file, path)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(OatError::Io(format!("Failed to create {}: {}", path.display(), e))),
+        }
+    }
+}
+
+fn md_action(c: &Context) -> Result<(), OatError> {
+    let source = c.args.first();
+    let markdown = read_file_or_stdin(source)?;
+    let open = c.bool_flag("open");
+
+    let mut body = String::new();
+    html::push_html(&mut body, Parser::new_ext(&markdown, options()));
+
+    // A page opened in the browser needs a <head> to get the encoding right.
+    let document = if c.bool_flag("standalone") || open {
+        let title = first_heading(&markdown)
+            .or_else(|| source.and_then(|path| Path::new(path).file_name()).map(|name| name.to_string_lossy().to_string()))
+            .unwrap_or_else(|| "Document".to_string());
+        standalone(&body, &title)
+    } else {
+        body
+    };
+
+    let path = match string_flag(c, "output")? {
+        Some(path) => {
+            let path = PathBuf::from(path);
+            fs::write(&path, document).map_err(|e| OatError::Io(format!("Failed to write {}: {}", path.display(), e)))?;
+            Some(path)
+        }
+        None if open => {
+            let (mut file, path) = create_preview_file(source)?;
+            file.write_all(document.as_bytes())
+                .map_err(|e| OatError::Io(format!("Failed to write {}: {}", path.display(), e)))?;
+            Some(path)
+        }
+        None => {
+            print!("{}", document);
+            None
+        }
+    };
+
+    if let Some(path) = path {
+        info(&format!("Wrote {}", path.display()));
+        if open {
+            opener::open(&path).map_err(|e| OatError::Failed(format!("Failed to open {}: {}", path.display(), e)))?;
+        }
+    }
+
+    Ok(())
+}
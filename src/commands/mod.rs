@@ -0,0 +1,27 @@
+pub mod base;
+pub mod case;
+pub mod char;
+pub mod cipher;
+pub mod color;
+pub mod config;
+pub mod convert;
+pub mod count;
+pub mod cron;
+pub mod csv;
+pub mod diff;
+pub mod dns;
+pub mod generate;
+pub mod http;
+pub mod ip;
+pub mod json;
+pub mod jwt;
+pub mod lorem;
+pub mod md;
+pub mod morse;
+pub mod pwhash;
+pub mod random;
+pub mod regex;
+pub mod slug;
+pub mod time;
+pub mod totp;
+pub mod xxd;
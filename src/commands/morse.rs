@@ -0,0 +1,145 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::string_flag;
+use crate::util::input::{read_file, read_text};
+use seahorse::{Command, Context, Flag, FlagType};
+
+const MORSE_TABLE: &[(char, &str)] = &[
+    ('A', ".-"), ('B', "-..."), ('C', "-.-."), ('D', "-.."), ('E', "."),
+    ('F', "..-."), ('G', "--."), ('H', "...."), ('I', ".."), ('J', ".---"),
+    ('K', "-.-"), ('L', ".-.."), ('M', "--"), ('N', "-."), ('O', "---"),
+    ('P', ".--."), ('Q', "--.-"), ('R', ".-."), ('S', "..."), ('T', "-"),
+    ('U', "..-"), ('V', "...-"), ('W', ".--"), ('X', "-..-"), ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"), ('1', ".----"), ('2', "..---"), ('3', "...--"), ('4', "....-"),
+    ('5', "....."), ('6', "-...."), ('7', "--..."), ('8', "---.."), ('9', "----."),
+    ('.', ".-.-.-"), (',', "--..--"), ('?', "..--.."), ('\'', ".----."), ('!', "-.-.--"),
+    ('/', "-..-."), ('(', "-.--."), (')', "-.--.-"), ('&', ".-..."), (':', "---..."),
+    (';', "-.-.-."), ('=', "-...-"), ('+', ".-.-."), ('-', "-....-"), ('_', "..--.-"),
+    ('"', ".-..-."), ('$', "...-..-"), ('@', ".--.-."),
+];
+
+pub fn morse_command() -> Command {
+    Command::new("morse")
+        .usage("oat morse [encode|decode]")
+        .command(encode_command())
+        .command(decode_command())
+}
+
+fn file_flag() -> Flag {
+    Flag::new("file", FlagType::String).alias("f").description("Read the input from a file")
+}
+
+fn encode_command() -> Command {
+    Command::new("encode")
+        .usage(r#"oat morse encode "[text]" [--file path]"#)
+        .flag(file_flag())
+        .action(|c| exit_on_error(encode_action(c)))
+}
+
+fn decode_command() -> Command {
+    Command::new("decode")
+        .usage(r#"oat morse decode "[... --- ...]" [--file path]"#)
+        .flag(file_flag())
+        .action(|c| exit_on_error(decode_action(c)))
+}
+
+fn read_input(c: &Context) -> Result<String, OatError> {
+    match string_flag(c, "file")? {
+        Some(path) => read_file(&path),
+        None => read_text(&c.args),
+    }
+}
+
+/// Encodes text with letters separated by spaces and words by " / ".
+fn encode(text: &str) -> Result<String, String> {
+    let mut words = Vec::new();
+    for word in text.split_whitespace() {
+        let letters = word
+            .chars()
+            .map(|ch| {
+                let upper = ch.to_ascii_uppercase();
+                MORSE_TABLE
+                    .iter()
+                    .find(|(letter, _)| *letter == upper)
+                    .map(|(_, code)| *code)
+                    .ok_or_else(|| format!("Character '{}' has no morse code", ch))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        words.push(letters.join(" "));
+    }
+    Ok(words.join(" / "))
+}
+
+/// Decodes morse where letters are separated by whitespace and words by
+/// `/`. Any amount of whitespace between symbols is accepted.
+fn decode(morse: &str) -> Result<String, String> {
+    let mut words = Vec::new();
+    for word in morse.split('/') {
+        let letters = word
+            .split_whitespace()
+            .map(|code| {
+                MORSE_TABLE
+                    .iter()
+                    .find(|(_, c)| *c == code)
+                    .map(|(letter, _)| *letter)
+                    .ok_or_else(|| format!("Unknown morse sequence '{}'", code))
+            })
+            .collect::<Result<String, _>>()?;
+        if !letters.is_empty() {
+            words.push(letters);
+        }
+    }
+    Ok(words.join(" "))
+}
+
+fn encode_action(c: &Context) -> Result<(), OatError> {
+    let text = read_input(c)?;
+    println!("{}", encode(&text).map_err(OatError::Usage)?);
+    Ok(())
+}
+
+fn decode_action(c: &Context) -> Result<(), OatError> {
+    let morse = read_input(c)?;
+    println!("{}", decode(&morse).map_err(OatError::Usage)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_letters_and_words() {
+        assert_eq!(encode("SOS").unwrap(), "... --- ...");
+        assert_eq!(encode("hello world").unwrap(), ".... . .-.. .-.. --- / .-- --- .-. .-.. -..");
+        assert_eq!(encode("  a   b  ").unwrap(), ".- / -...");
+    }
+
+    #[test]
+    fn round_trips() {
+        let text = "THE QUICK BROWN FOX 123 A/B?";
+        assert_eq!(decode(&encode(text).unwrap()).unwrap(), text);
+    }
+
+    #[test]
+    fn decode_tolerates_extra_whitespace() {
+        assert_eq!(decode("  ...   ---\t...\n").unwrap(), "SOS");
+        assert_eq!(decode(".... ..   /   .- -...").unwrap(), "HI AB");
+        assert_eq!(decode(".... ../.-").unwrap(), "HI A");
+    }
+
+    #[test]
+    fn decode_ignores_stray_slashes() {
+        assert_eq!(decode("/ .... .. //  / .- /").unwrap(), "HI A");
+        assert_eq!(decode("/").unwrap(), "");
+        assert_eq!(decode("").unwrap(), "");
+    }
+
+    #[test]
+    fn reports_unknown_input() {
+        assert_eq!(encode("hi#").unwrap_err(), "Character '#' has no morse code");
+        assert_eq!(encode("é").unwrap_err(), "Character 'é' has no morse code");
+        assert_eq!(decode("... ------- ...").unwrap_err(), "Unknown morse sequence '-------'");
+        assert_eq!(decode(".- abc").unwrap_err(), "Unknown morse sequence 'abc'");
+    }
+}
@@ -0,0 +1,96 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::{string_flag, uint_flag};
+use crate::util::input::read_text;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use argon2::{Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version};
+use seahorse::{Command, Context, Flag, FlagType};
+
+pub fn pwhash_command() -> Command {
+    Command::new("pwhash")
+        .usage("oat pwhash [bcrypt|argon2]")
+        .command(bcrypt_command())
+        .command(argon2_command())
+}
+
+fn verify_flag() -> Flag {
+    Flag::new("verify", FlagType::String).description("Check the password against this hash instead of hashing it")
+}
+
+fn bcrypt_command() -> Command {
+    Command::new("bcrypt")
+        .usage(r#"oat pwhash bcrypt "[password]" [--cost 4-31] [--verify hash]"#)
+        .flag(Flag::new("cost", FlagType::Uint).description("bcrypt cost factor (default: 12)"))
+        .flag(verify_flag())
+        .action(|c| exit_on_error(bcrypt_action(c)))
+}
+
+fn argon2_command() -> Command {
+    Command::new("argon2")
+        .usage(r#"oat pwhash argon2 "[password]" [--memory KiB] [--verify hash]"#)
+        .flag(Flag::new("memory", FlagType::Uint).description("Memory cost in KiB (default: 19456)"))
+        .flag(verify_flag())
+        .action(|c| exit_on_error(argon2_action(c)))
+}
+
+/// Reads the password from the arguments or stdin, dropping the trailing
+/// newline that `echo` and most editors add.
+fn read_password(c: &Context) -> Result<String, OatError> {
+    let password = read_text(&c.args)?;
+    Ok(password.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn report_match(matches: bool) -> Result<(), OatError> {
+    if matches {
+        println!("Password matches");
+        Ok(())
+    } else {
+        Err(OatError::Failed("Password does not match".to_string()))
+    }
+}
+
+fn bcrypt_action(c: &Context) -> Result<(), OatError> {
+    let password = read_password(c)?;
+
+    if let Some(hash) = string_flag(c, "verify")? {
+        let matches = bcrypt::verify(&password, &hash)
+            .map_err(|e| OatError::Usage(format!("Invalid bcrypt hash: {}", e)))?;
+        return report_match(matches);
+    }
+
+    let cost = uint_flag(c, "cost")?.unwrap_or(bcrypt::DEFAULT_COST as usize);
+    if !(4..=31).contains(&cost) {
+        return Err(OatError::Usage("--cost must be between 4 and 31".to_string()));
+    }
+
+    let hash = bcrypt::hash(&password, cost as u32)
+        .map_err(|e| OatError::Failed(format!("Failed to hash password: {}", e)))?;
+    println!("{}", hash);
+
+    Ok(())
+}
+
+fn argon2_action(c: &Context) -> Result<(), OatError> {
+    let password = read_password(c)?;
+
+    if let Some(hash) = string_flag(c, "verify")? {
+        let parsed = PasswordHash::new(&hash)
+            .map_err(|e| OatError::Usage(format!("Invalid argon2 hash: {}", e)))?;
+        let matches = Argon2::default().verify_password(password.as_bytes(), &parsed).is_ok();
+        return report_match(matches);
+    }
+
+    let memory = uint_flag(c, "memory")?.unwrap_or(Params::DEFAULT_M_COST as usize);
+    let memory = u32::try_from(memory)
+        .map_err(|_| OatError::Usage(format!("--memory must be at most {} KiB", u32::MAX)))?;
+    let params = Params::new(memory, Params::DEFAULT_T_COST, Params::DEFAULT_P_COST, None)
+        .map_err(|e| OatError::Usage(format!("Invalid --memory: {}", e)))?;
+
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| OatError::Failed(format!("Failed to hash password: {}", e)))?;
+    println!("{}", hash);
+
+    Ok(())
+}
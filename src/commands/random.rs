@@ -0,0 +1,80 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::{int_flag, string_flag, uint_flag};
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use data_encoding::HEXLOWER;
+use rand::rngs::OsRng;
+use rand::{Rng, RngCore};
+use seahorse::{Command, Context, Flag, FlagType};
+
+/// Upper bound for `random bytes`, since the output is built in memory.
+const MAX_BYTES: usize = 1024 * 1024;
+
+pub fn random_command() -> Command {
+    Command::new("random")
+        .usage("oat random [bytes|int]")
+        .command(bytes_command())
+        .command(int_command())
+}
+
+fn bytes_command() -> Command {
+    Command::new("bytes")
+        .usage("oat random bytes [length (max 1048576)] [--format hex|base64|base64-std]")
+        .flag(Flag::new("format", FlagType::String).alias("f").description("Output encoding: hex, base64 (URL-safe) or base64-std (default: hex)"))
+        .action(|c| exit_on_error(bytes_action(c)))
+}
+
+fn int_command() -> Command {
+    Command::new("int")
+        .usage("oat random int [--min N] [--max N] [--count N]")
+        .flag(Flag::new("min", FlagType::Int).description("Smallest possible value (default: 0)"))
+        .flag(Flag::new("max", FlagType::Int).description("Largest possible value (default: 100)"))
+        .flag(Flag::new("count", FlagType::Uint).alias("c").description("Number of values to generate (default: 1)"))
+        .action(|c| exit_on_error(int_action(c)))
+}
+
+fn bytes_action(c: &Context) -> Result<(), OatError> {
+    let length = match c.args.first() {
+        Some(arg) => arg
+            .parse::<usize>()
+            .map_err(|_| OatError::Usage(format!("Invalid length: {}", arg)))?,
+        None => 32,
+    };
+    if length > MAX_BYTES {
+        return Err(OatError::Usage(format!("Length must be at most {} bytes", MAX_BYTES)));
+    }
+
+    let mut bytes = vec![0u8; length];
+    OsRng.fill_bytes(&mut bytes);
+
+    let format = string_flag(c, "format")?.unwrap_or_else(|| "hex".to_string());
+    let encoded = match format.as_str() {
+        "hex" => HEXLOWER.encode(&bytes),
+        "base64" => URL_SAFE_NO_PAD.encode(&bytes),
+        "base64-std" => STANDARD.encode(&bytes),
+        _ => {
+            return Err(OatError::Usage(format!(
+                "Unknown format '{}'. Use hex, base64 or base64-std",
+                format
+            )))
+        }
+    };
+    println!("{}", encoded);
+
+    Ok(())
+}
+
+fn int_action(c: &Context) -> Result<(), OatError> {
+    let min = int_flag(c, "min")?.unwrap_or(0);
+    let max = int_flag(c, "max")?.unwrap_or(100);
+    if min > max {
+        return Err(OatError::Usage(format!("--min ({}) must not be greater than --max ({})", min, max)));
+    }
+
+    let count = uint_flag(c, "count")?.unwrap_or(1);
+    for _ in 0..count {
+        println!("{}", OsRng.gen_range(min..=max));
+    }
+
+    Ok(())
+}
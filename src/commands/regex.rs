@@ -0,0 +1,72 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::string_flag;
+use crate::util::input::read_file;
+use regex::RegexBuilder;
+use seahorse::{Command, Context, Flag, FlagType};
+
+pub fn regex_command() -> Command {
+    Command::new("regex")
+        .usage(r#"oat regex "[pattern]" "[text]" [--file path] [--replace repl] [-i] [-g]"#)
+        .flag(Flag::new("file", FlagType::String).alias("f").description("Match against the contents of a file"))
+        .flag(Flag::new("replace", FlagType::String).alias("r").description("Show the text with matches replaced ($1, $name refer to groups)"))
+        .flag(Flag::new("ignore-case", FlagType::Bool).alias("i").description("Match case-insensitively"))
+        .flag(Flag::new("global", FlagType::Bool).alias("g").description("Show and replace every match instead of only the first"))
+        .action(|c| exit_on_error(regex_action(c)))
+}
+
+fn regex_action(c: &Context) -> Result<(), OatError> {
+    let pattern = c
+        .args
+        .first()
+        .ok_or_else(|| OatError::Usage("Please provide a pattern".to_string()))?;
+
+    let text = match string_flag(c, "file")? {
+        Some(path) => read_file(&path)?,
+        None if c.args.len() > 1 => c.args[1..].join(" "),
+        None => return Err(OatError::Usage("Please provide text or --file to match against".to_string())),
+    };
+
+    let regex = RegexBuilder::new(pattern)
+        .case_insensitive(c.bool_flag("ignore-case"))
+        .build()
+        .map_err(|e| OatError::Usage(format!("Invalid pattern: {}", e)))?;
+
+    let global = c.bool_flag("global");
+    let limit = if global { usize::MAX } else { 1 };
+    let names: Vec<Option<&str>> = regex.capture_names().collect();
+
+    let mut found = 0;
+    for captures in regex.captures_iter(&text).take(limit) {
+        found += 1;
+        if let Some(whole) = captures.get(0) {
+            println!("Match {}: {:?} at {}..{}", found, whole.as_str(), whole.start(), whole.end());
+        }
+
+        for (index, group) in captures.iter().enumerate().skip(1) {
+            let label = match names[index] {
+                Some(name) => format!("{} ({})", index, name),
+                None => index.to_string(),
+            };
+            match group {
+                Some(m) => println!("  Group {}: {:?} at {}..{}", label, m.as_str(), m.start(), m.end()),
+                None => println!("  Group {}: <no match>", label),
+            }
+        }
+    }
+
+    if found == 0 {
+        return Err(OatError::Failed("No match".to_string()));
+    }
+
+    if let Some(replacement) = string_flag(c, "replace")? {
+        let replaced = if global {
+            regex.replace_all(&text, replacement.as_str())
+        } else {
+            regex.replace(&text, replacement.as_str())
+        };
+        println!();
+        println!("Replaced: {}", replaced);
+    }
+
+    Ok(())
+}
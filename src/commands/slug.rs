@@ -0,0 +1,46 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::uint_flag;
+use crate::util::input::read_text;
+use deunicode::deunicode;
+use seahorse::{Command, Context, Flag, FlagType};
+
+pub fn slug_command() -> Command {
+    Command::new("slug")
+        .usage(r#"oat slug "[text]" [--max-len N]"#)
+        .flag(Flag::new("max-len", FlagType::Uint).description("Truncate the slug at a word boundary to at most N characters"))
+        .action(|c| exit_on_error(slug_action(c)))
+}
+
+fn slugify(text: &str) -> String {
+    let ascii = deunicode(text).to_lowercase();
+    ascii
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Cuts a slug down to `max_len` characters without splitting a word. A
+/// single word longer than `max_len` is cut hard.
+fn truncate_slug(slug: &str, max_len: usize) -> &str {
+    if slug.len() <= max_len {
+        return slug;
+    }
+
+    match slug[..=max_len].rfind('-') {
+        Some(end) if end > 0 => &slug[..end],
+        _ => &slug[..max_len],
+    }
+}
+
+fn slug_action(c: &Context) -> Result<(), OatError> {
+    let text = read_text(&c.args)?;
+    let slug = slugify(&text);
+
+    match uint_flag(c, "max-len")? {
+        Some(max_len) => println!("{}", truncate_slug(&slug, max_len)),
+        None => println!("{}", slug),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Local, SecondsFormat, TimeZone, Utc};
+use crate::error::{exit_on_error, OatError};
+use seahorse::{Command, Context, Flag, FlagType};
+
+pub fn time_command() -> Command {
+    Command::new("time")
+        .usage("oat time [now|to|from]")
+        .command(now_command())
+        .command(to_command())
+        .command(from_command())
+}
+
+fn millis_flag() -> Flag {
+    Flag::new("millis", FlagType::Bool)
+        .alias("m")
+        .description("Use millisecond timestamps instead of seconds")
+}
+
+fn now_command() -> Command {
+    Command::new("now")
+        .usage("oat time now [--millis]")
+        .flag(millis_flag())
+        .action(now_action)
+}
+
+fn to_command() -> Command {
+    Command::new("to")
+        .usage("oat time to [epoch] [--millis]")
+        .flag(millis_flag())
+        .action(|c| exit_on_error(to_action(c)))
+}
+
+fn from_command() -> Command {
+    Command::new("from")
+        .usage(r#"oat time from "[RFC3339 date]" [--millis]"#)
+        .flag(millis_flag())
+        .action(|c| exit_on_error(from_action(c)))
+}
+
+fn epoch(date: &DateTime<Utc>, millis: bool) -> i64 {
+    if millis {
+        date.timestamp_millis()
+    } else {
+        date.timestamp()
+    }
+}
+
+fn now_action(c: &Context) {
+    let now = Utc::now();
+    println!("Unix:     {}", epoch(&now, c.bool_flag("millis")));
+    println!("ISO-8601: {}", now.to_rfc3339_opts(SecondsFormat::Secs, true));
+}
+
+fn to_action(c: &Context) -> Result<(), OatError> {
+    let input = c
+        .args
+        .first()
+        .ok_or_else(|| OatError::Usage("Please provide a unix timestamp".to_string()))?;
+    let value: i64 = input
+        .parse()
+        .map_err(|_| OatError::Usage(format!("Invalid timestamp: {}", input)))?;
+
+    let date = if c.bool_flag("millis") {
+        Utc.timestamp_millis_opt(value).single()
+    } else {
+        Utc.timestamp_opt(value, 0).single()
+    }
+    .ok_or_else(|| OatError::Usage(format!("Timestamp out of range: {}", value)))?;
+
+    println!("UTC:   {}", date.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+    println!("Local: {}", date.with_timezone(&Local).to_rfc3339_opts(SecondsFormat::AutoSi, false));
+
+    Ok(())
+}
+
+fn from_action(c: &Context) -> Result<(), OatError> {
+    let input = c.args.join(" ");
+    if input.is_empty() {
+        return Err(OatError::Usage(
+            "Please provide a date, e.g. 2024-06-03T12:00:00Z".to_string(),
+        ));
+    }
+
+    let date = DateTime::parse_from_rfc3339(&input)
+        .map_err(|e| OatError::Usage(format!("Invalid RFC3339 date '{}': {}", input, e)))?
+        .with_timezone(&Utc);
+
+    println!("{}", epoch(&date, c.bool_flag("millis")));
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use chrono::Utc;
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::{string_flag, uint_flag};
+use crate::util::output::info;
+use seahorse::{Command, Context, Flag, FlagType};
+use sha1::Sha1;
+use sha2::Sha256;
+use std::fs;
+
+pub fn totp_command() -> Command {
+    Command::new("totp")
+        .usage("oat totp [base32-secret] [--digits N] [--period N] [--algo sha1|sha256] [--file path]")
+        .flag(Flag::new("digits", FlagType::Uint).description("Number of digits in the code, 6-8 (default: 6)"))
+        .flag(Flag::new("period", FlagType::Uint).description("Time step in seconds (default: 30)"))
+        .flag(Flag::new("algo", FlagType::String).description("HMAC algorithm: sha1 or sha256 (default: sha1)"))
+        .flag(Flag::new("file", FlagType::String).alias("f").description("Read the secret from a file instead of the arguments"))
+        .action(|c| exit_on_error(totp_action(c)))
+}
+
+fn decode_secret(secret: &str) -> Result<Vec<u8>, String> {
+    let normalized: String = secret
+        .chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != '=' && *ch != '-')
+        .collect::<String>()
+        .to_uppercase();
+
+    if normalized.is_empty() {
+        return Err("Please provide a base32 secret".to_string());
+    }
+
+    BASE32_NOPAD
+        .decode(normalized.as_bytes())
+        .map_err(|e| format!("Invalid base32 secret: {}", e))
+}
+
+fn hmac_digest(algo: &str, key: &[u8], message: &[u8]) -> Result<Vec<u8>, String> {
+    match algo {
+        "sha1" => {
+            let mut mac = Hmac::<Sha1>::new_from_slice(key).map_err(|e| e.to_string())?;
+            mac.update(message);
+            Ok(mac.finalize().into_bytes().to_vec())
+        }
+        "sha256" => {
+            let mut mac = Hmac::<Sha256>::new_from_slice(key).map_err(|e| e.to_string())?;
+            mac.update(message);
+            Ok(mac.finalize().into_bytes().to_vec())
+        }
+        _ => Err(format!("Unknown algorithm '{}'. Use sha1 or sha256", algo)),
+    }
+}
+
+/// Computes the RFC 6238 code for the given time step counter.
+fn generate_code(algo: &str, key: &[u8], counter: u64, digits: u32) -> Result<String, String> {
+    let digest = hmac_digest(algo, key, &counter.to_be_bytes())?;
+
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+
+    let code = binary % 10u32.pow(digits);
+    Ok(format!("{:0width$}", code, width = digits as usize))
+}
+
+fn totp_action(c: &Context) -> Result<(), OatError> {
+    let secret = match string_flag(c, "file")? {
+        Some(path) => fs::read_to_string(&path)
+            .map_err(|e| OatError::Io(format!("Failed to read {}: {}", path, e)))?,
+        None => c.args.join(""),
+    };
+    let key = decode_secret(&secret).map_err(OatError::Usage)?;
+
+    let digits = uint_flag(c, "digits")?.unwrap_or(6);
+    if !(6..=8).contains(&digits) {
+        return Err(OatError::Usage("--digits must be between 6 and 8".to_string()));
+    }
+
+    let period = uint_flag(c, "period")?.unwrap_or(30) as u64;
+    if period == 0 {
+        return Err(OatError::Usage("--period must be greater than 0".to_string()));
+    }
+
+    let algo = string_flag(c, "algo")?.unwrap_or_else(|| "sha1".to_string()).to_lowercase();
+
+    let now = Utc::now().timestamp() as u64;
+    let code = generate_code(&algo, &key, now / period, digits as u32).map_err(OatError::Usage)?;
+    let remaining = period - now % period;
+
+    println!("{}", code);
+    info(&format!("Expires in {}s", remaining));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from RFC 6238, Appendix B (8 digits, 30 second steps).
+    const TIMES: [u64; 6] = [59, 1111111109, 1111111111, 1234567890, 2000000000, 20000000000];
+
+    fn codes(algo: &str, key: &[u8]) -> Vec<String> {
+        TIMES.iter().map(|time| generate_code(algo, key, time / 30, 8).unwrap()).collect()
+    }
+
+    #[test]
+    fn matches_rfc_6238_sha1_vectors() {
+        assert_eq!(
+            codes("sha1", b"12345678901234567890"),
+            ["94287082", "07081804", "14050471", "89005924", "69279037", "65353130"]
+        );
+    }
+
+    #[test]
+    fn matches_rfc_6238_sha256_vectors() {
+        assert_eq!(
+            codes("sha256", b"12345678901234567890123456789012"),
+            ["46119246", "68084774", "67062674", "91819424", "90698825", "77737706"]
+        );
+    }
+
+    #[test]
+    fn pads_short_codes() {
+        assert_eq!(generate_code("sha1", b"12345678901234567890", 1111111109 / 30, 6).unwrap(), "081804");
+    }
+
+    #[test]
+    fn decodes_base32_secrets() {
+        assert_eq!(decode_secret("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ").unwrap(), b"12345678901234567890");
+        assert_eq!(decode_secret("gezd gnbv-gy3t qojq gezd gnbv gy3t qojq").unwrap(), b"12345678901234567890");
+        assert!(decode_secret("").is_err());
+        assert!(decode_secret("not base32!").is_err());
+        assert!(generate_code("md5", b"key", 0, 6).is_err());
+    }
+}
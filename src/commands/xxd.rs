@@ -0,0 +1,103 @@
+use crate::error::{exit_on_error, OatError};
+use crate::util::args::uint_flag;
+use seahorse::{Command, Context, Flag, FlagType};
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
+
+pub fn xxd_command() -> Command {
+    Command::new("xxd")
+        .usage("oat xxd [file] [--cols N] [--group N] [--plain]")
+        .flag(Flag::new("cols", FlagType::Uint).alias("c").description("Bytes per line (default: 16, or 30 with --plain)"))
+        .flag(Flag::new("group", FlagType::Uint).alias("g").description("Bytes per space-separated group, 0 for no grouping (default: 2)"))
+        .flag(Flag::new("plain", FlagType::Bool).alias("p").description("Print only hex, without offsets or the ASCII column"))
+        .action(|c| exit_on_error(xxd_action(c)))
+}
+
+fn open_input(path: Option<&String>) -> Result<Box<dyn Read>, OatError> {
+    match path {
+        Some(path) if path != "-" => {
+            let file = File::open(path).map_err(|e| OatError::Io(format!("Failed to read {}: {}", path, e)))?;
+            Ok(Box::new(file))
+        }
+        _ if io::stdin().is_terminal() => {
+            Err(OatError::Usage("Please provide a file or pipe input via stdin".to_string()))
+        }
+        _ => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+/// Fills `buffer` as far as possible, returning fewer bytes only at the end
+/// of the input.
+fn read_chunk(reader: &mut dyn Read, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+fn hex_column(bytes: &[u8], cols: usize, group: usize) -> String {
+    let mut hex = String::new();
+    for i in 0..cols {
+        if i > 0 && group > 0 && i % group == 0 {
+            hex.push(' ');
+        }
+        match bytes.get(i) {
+            Some(byte) => hex.push_str(&format!("{:02x}", byte)),
+            None => hex.push_str("  "),
+        }
+    }
+    hex
+}
+
+fn ascii_column(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&byte| if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' })
+        .collect()
+}
+
+fn xxd_action(c: &Context) -> Result<(), OatError> {
+    let plain = c.bool_flag("plain");
+    let cols = uint_flag(c, "cols")?.unwrap_or(if plain { 30 } else { 16 });
+    let group = uint_flag(c, "group")?.unwrap_or(2);
+    if cols == 0 {
+        return Err(OatError::Usage("--cols must be at least 1".to_string()));
+    }
+
+    let mut reader = open_input(c.args.first())?;
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut buffer = vec![0u8; cols];
+    let mut offset = 0usize;
+
+    loop {
+        let read = read_chunk(&mut reader, &mut buffer).map_err(|e| OatError::Io(format!("Failed to read input: {}", e)))?;
+        if read == 0 {
+            break;
+        }
+        let bytes = &buffer[..read];
+
+        let line = if plain {
+            bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+        } else {
+            format!("{:08x}: {}  {}", offset, hex_column(bytes, cols, group), ascii_column(bytes))
+        };
+        // A closed pipe (e.g. `| head`) just ends the dump.
+        if writeln!(out, "{}", line).is_err() {
+            return Ok(());
+        }
+
+        offset += read;
+        if read < cols {
+            break;
+        }
+    }
+
+    out.flush().ok();
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::error::OatError;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Persistent settings stored in `config.json` under [`oat_dir`]. Every
+/// field has a default so older or partial files keep loading.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Disable colored output, like passing `--no-color` to every command.
+    pub no_color: bool,
+    /// Suppress decorative output, like passing `--quiet` to every command.
+    pub quiet: bool,
+}
+
+const CONFIG_FILE: &str = "config.json";
+
+/// Base directory for all of oat's files: `$OAT_HOME` when set, otherwise
+/// `~/.oat`.
+pub fn oat_dir() -> PathBuf {
+    match env::var_os("OAT_HOME") {
+        Some(home) if !home.is_empty() => PathBuf::from(home),
+        _ => dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".oat"),
+    }
+}
+
+/// Path of a state file called `name` inside [`oat_dir`].
+pub fn config_file(name: &str) -> PathBuf {
+    oat_dir().join(name)
+}
+
+/// Loads a JSON state file from [`oat_dir`], falling back to the default
+/// value when the file doesn't exist yet.
+pub fn load_json<T: DeserializeOwned + Default>(name: &str) -> Result<T, OatError> {
+    let path = config_file(name);
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| OatError::Io(format!("Failed to read {}: {}", path.display(), e)))?;
+    serde_json::from_str(&contents)
+        .map_err(|e| OatError::Failed(format!("Invalid file {}: {}", path.display(), e)))
+}
+
+/// Writes a JSON state file to [`oat_dir`], creating the directory if needed.
+pub fn save_json<T: Serialize>(name: &str, value: &T) -> Result<(), OatError> {
+    let dir = oat_dir();
+    fs::create_dir_all(&dir)
+        .map_err(|e| OatError::Io(format!("Failed to create {}: {}", dir.display(), e)))?;
+
+    let path = config_file(name);
+    let contents = serde_json::to_string_pretty(value)
+        .map_err(|e| OatError::Failed(format!("Failed to serialize {}: {}", name, e)))?;
+    fs::write(&path, contents)
+        .map_err(|e| OatError::Io(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+pub fn get_config_file_path() -> PathBuf {
+    config_file(CONFIG_FILE)
+}
+
+pub fn load_config() -> Result<Config, OatError> {
+    load_json(CONFIG_FILE)
+}
+
+pub fn save_config(config: &Config) -> Result<(), OatError> {
+    save_json(CONFIG_FILE, config)
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+use std::process;
+
+/// Error returned by command actions. Each variant maps to its own exit code
+/// so scripts can tell bad input apart from runtime failures.
+#[derive(Debug)]
+pub enum OatError {
+    /// Missing or invalid arguments, flags or input (exit code 2).
+    Usage(String),
+    /// Reading or writing files or streams failed (exit code 3).
+    Io(String),
+    /// A network request failed or returned an error (exit code 4).
+    Network(String),
+    /// Any other failure (exit code 1).
+    Failed(String),
+}
+
+impl OatError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            OatError::Failed(_) => 1,
+            OatError::Usage(_) => 2,
+            OatError::Io(_) => 3,
+            OatError::Network(_) => 4,
+        }
+    }
+}
+
+impl fmt::Display for OatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OatError::Usage(msg)
+            | OatError::Io(msg)
+            | OatError::Network(msg)
+            | OatError::Failed(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Prints a failed action's error to stderr and exits with its exit code.
+pub fn exit_on_error(result: Result<(), OatError>) {
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(e.exit_code());
+    }
+}
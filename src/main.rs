@@ -1,85 +1,105 @@
-use seahorse::{App, Command};
+use seahorse::{App, Flag, FlagType};
 use std::env;
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use tokio;
 
-#[tokio::main]
-async fn main() {
-    let args: Vec<String> = env::args().collect();
-    let app = App::new(env!("CARGO_PKG_NAME"))
-        .description(env!("CARGO_PKG_DESCRIPTION"))
-        .author(env!("CARGO_PKG_AUTHORS"))
-        .version(env!("CARGO_PKG_VERSION"))
-        .usage("oat [name]")
-        .command(generate_command());
-
-    app.run(args);
-}
+mod commands;
+mod config;
+mod error;
+mod util;
 
-fn generate_command() -> Command {
-    Command::new("generate")
-        .usage("oat generate [subcommand]")
-        .command(dalle_command())
-}
-
-fn dalle_command() -> Command {
-    Command::new("dalle")
-        .usage(r#"oat generate dalle "[prompt]""#)
-        .action(|c| {
-            let prompt: String = c.args.join(" ");
-            tokio::spawn(async move {
-                dalle_action(prompt).await;
-            });
-        })
-}
+use commands::base::base_command;
+use commands::case::case_command;
+use commands::char::char_command;
+use commands::cipher::cipher_command;
+use commands::color::color_command;
+use commands::config::config_command;
+use commands::convert::convert_command;
+use commands::count::count_command;
+use commands::cron::cron_command;
+use commands::csv::csv_command;
+use commands::diff::diff_command;
+use commands::dns::dns_command;
+use commands::generate::generate_command;
+use commands::http::http_command;
+use commands::ip::ip_command;
+use commands::jwt::jwt_command;
+use commands::json::json_command;
+use commands::lorem::lorem_command;
+use commands::md::md_command;
+use commands::morse::morse_command;
+use commands::pwhash::pwhash_command;
+use commands::random::random_command;
+use commands::regex::regex_command;
+use commands::slug::slug_command;
+use commands::time::time_command;
+use commands::totp::totp_command;
+use commands::xxd::xxd_command;
 
-#[derive(Serialize)]
-struct DalleRequest {
-    model: String,
-    prompt: String,
-    n: u32,
-    size: String,
-}
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
 
-#[derive(Deserialize)]
-struct DalleResponse {
-    data: Vec<ImageData>,
-}
-
-#[derive(Deserialize)]
-struct ImageData {
-    url: String,
-}
-
-async fn dalle_action(prompt: String) {
-    let api_key = env::var("OPENAI_API_KEY").expect("OPENAI_API_KEY must be set");
-
-    let client = Client::new();
-    let request_body = DalleRequest {
-        model: "dall-e-3".to_string(),
-        prompt: prompt.clone(),
-        n: 1,
-        size: "1024x1024".to_string(),
-    };
-
-    let response = client
-        .post("https://api.openai.com/v1/images/generations")
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", api_key))
-        .json(&request_body)
-        .send()
-        .await
-        .expect("Failed to send request");
+    if let Ok(config) = config::load_config() {
+        if config.no_color {
+            util::output::disable_color();
+        }
+        if config.quiet {
+            util::output::enable_quiet();
+        }
+    }
 
-    if response.status().is_success() {
-        let dalle_response: DalleResponse = response.json().await.expect("Failed to parse response");
-        if let Some(image_data) = dalle_response.data.first() {
-            println!("{}", image_data.url);
-        } else {
-            eprintln!("No image data found in the response");
+    // Global flags apply to every command, so they are handled here rather
+    // than being declared on each subcommand. Only flags before the command
+    // name count, so text like `oat count "--quiet"` is left alone.
+    let mut index = 1;
+    while let Some(arg) = args.get(index) {
+        match arg.as_str() {
+            "--no-color" => {
+                args.remove(index);
+                util::output::disable_color();
+            }
+            "--quiet" => {
+                args.remove(index);
+                util::output::enable_quiet();
+            }
+            "--" => break,
+            flag if flag.starts_with('-') => index += 1,
+            _ => break,
         }
-    } else {
-        eprintln!("Failed to generate image: {}", response.status());
     }
+
+    let app = App::new(env!("CARGO_PKG_NAME"))
+        .description(env!("CARGO_PKG_DESCRIPTION"))
+        .author(env!("CARGO_PKG_AUTHORS"))
+        .version(env!("CARGO_PKG_VERSION"))
+        .usage("oat [--no-color] [--quiet] [name]")
+        .flag(Flag::new("no-color", FlagType::Bool).description("Disable colored output (NO_COLOR is honored too)"))
+        .flag(Flag::new("quiet", FlagType::Bool).description("Only print the essential result of a command"))
+        .command(generate_command())
+        .command(time_command())
+        .command(jwt_command())
+        .command(color_command())
+        .command(base_command())
+        .command(cipher_command())
+        .command(totp_command())
+        .command(case_command())
+        .command(slug_command())
+        .command(config_command())
+        .command(pwhash_command())
+        .command(random_command())
+        .command(dns_command())
+        .command(http_command())
+        .command(count_command())
+        .command(diff_command())
+        .command(morse_command())
+        .command(convert_command())
+        .command(regex_command())
+        .command(ip_command())
+        .command(cron_command())
+        .command(lorem_command())
+        .command(csv_command())
+        .command(json_command())
+        .command(xxd_command())
+        .command(md_command())
+        .command(char_command());
+
+    app.run(args);
 }
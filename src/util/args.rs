@@ -0,0 +1,61 @@
+use crate::error::OatError;
+use seahorse::error::FlagError;
+use seahorse::Context;
+
+/// Collects every value of a repeatable flag from the leftover arguments.
+///
+/// seahorse only parses the first occurrence of a flag and leaves later ones
+/// in `Context::args`, so this removes the remaining `name value` pairs and
+/// returns their values alongside the arguments that are left.
+pub fn take_repeated(args: &[String], names: &[&str]) -> (Vec<String>, Vec<String>) {
+    let mut values = Vec::new();
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        if names.contains(&arg.as_str()) {
+            if let Some(value) = iter.next() {
+                values.push(value.clone());
+            }
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+
+    (values, rest)
+}
+
+/// Turns a seahorse flag lookup into `Ok(None)` when the flag wasn't given
+/// and a usage error when it was given without a valid value, rather than
+/// silently falling back to a default.
+fn optional_flag<T>(result: Result<T, FlagError>, name: &str, expected: &str) -> Result<Option<T>, OatError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(FlagError::NotFound) => Ok(None),
+        Err(FlagError::ArgumentError) => Err(OatError::Usage(format!("--{} needs a value", name))),
+        Err(_) => Err(OatError::Usage(format!("Invalid value for --{}: expected {}", name, expected))),
+    }
+}
+
+pub fn string_flag(c: &Context, name: &str) -> Result<Option<String>, OatError> {
+    optional_flag(c.string_flag(name), name, "text")
+}
+
+pub fn uint_flag(c: &Context, name: &str) -> Result<Option<usize>, OatError> {
+    optional_flag(c.uint_flag(name), name, "a non-negative whole number")
+}
+
+pub fn int_flag(c: &Context, name: &str) -> Result<Option<isize>, OatError> {
+    optional_flag(c.int_flag(name), name, "a whole number")
+}
+
+/// Reads the `--delimiter` flag, which must be a single ASCII character or
+/// `tab`, defaulting to a comma.
+pub fn delimiter(c: &Context) -> Result<u8, OatError> {
+    let value = string_flag(c, "delimiter")?.unwrap_or_else(|| ",".to_string());
+    match value.as_str() {
+        "tab" | "\\t" => Ok(b'\t'),
+        _ if value.len() == 1 && value.is_ascii() => Ok(value.as_bytes()[0]),
+        _ => Err(OatError::Usage(format!("Invalid delimiter '{}': expected a single ASCII character", value))),
+    }
+}
@@ -0,0 +1,40 @@
+use crate::error::OatError;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+
+/// Returns the positional arguments joined by spaces, or the contents of
+/// stdin when no arguments were given and input is being piped in.
+pub fn read_text(args: &[String]) -> Result<String, OatError> {
+    if !args.is_empty() {
+        return Ok(args.join(" "));
+    }
+
+    read_stdin("Please provide some text or pipe it via stdin")
+}
+
+/// Reads the file at `path`, or stdin when no path (or `-`) was given and
+/// input is being piped in.
+pub fn read_file_or_stdin(path: Option<&String>) -> Result<String, OatError> {
+    match path {
+        Some(path) if path != "-" => read_file(path),
+        _ => read_stdin("Please provide a file or pipe input via stdin"),
+    }
+}
+
+fn read_stdin(missing_message: &str) -> Result<String, OatError> {
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Err(OatError::Usage(missing_message.to_string()));
+    }
+
+    let mut text = String::new();
+    stdin
+        .lock()
+        .read_to_string(&mut text)
+        .map_err(|e| OatError::Io(format!("Failed to read stdin: {}", e)))?;
+    Ok(text)
+}
+
+pub fn read_file(path: &str) -> Result<String, OatError> {
+    fs::read_to_string(path).map_err(|e| OatError::Io(format!("Failed to read {}: {}", path, e)))
+}
@@ -0,0 +1,14 @@
+pub mod args;
+pub mod input;
+pub mod output;
+
+use crate::error::OatError;
+use std::future::Future;
+
+/// Runs an async action to completion on a fresh Tokio runtime, for the
+/// commands whose work is async while `main` stays synchronous.
+pub fn block_on<T>(future: impl Future<Output = Result<T, OatError>>) -> Result<T, OatError> {
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| OatError::Failed(format!("Failed to start async runtime: {}", e)))?;
+    runtime.block_on(future)
+}
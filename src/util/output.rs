@@ -0,0 +1,47 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static NO_COLOR: AtomicBool = AtomicBool::new(false);
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Disables colored output for the rest of the process, as requested by the
+/// global `--no-color` flag.
+pub fn disable_color() {
+    NO_COLOR.store(true, Ordering::Relaxed);
+}
+
+/// Returns whether the user allows colors at all. Colors are off when
+/// `--no-color` was passed or `NO_COLOR` is set to a non-empty value (see
+/// https://no-color.org). Use this for commands with an explicit `--color`.
+pub fn color_allowed() -> bool {
+    if NO_COLOR.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+/// Returns whether ANSI colors should be written to stdout by default: the
+/// user allows them and stdout is a terminal.
+pub fn should_colorize() -> bool {
+    color_allowed() && io::stdout().is_terminal()
+}
+
+/// Suppresses decorative output for the rest of the process, as requested by
+/// the global `--quiet` flag.
+pub fn enable_quiet() {
+    QUIET.store(true, Ordering::Relaxed);
+}
+
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Prints a decorative line such as a note, tip or status message. Nothing
+/// is printed when `--quiet` is set, leaving only the essential result.
+pub fn info(message: &str) {
+    if !is_quiet() {
+        println!("{}", message);
+    }
+}
@@ -0,0 +1,64 @@
+use std::env;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::process::Command;
+use std::thread;
+
+/// Serves a single canned response to the first request and returns the
+/// request line that was received.
+fn mock_server(body: &'static str) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            if header == "\r\n" {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut request_body = vec![0; content_length];
+        reader.read_exact(&mut request_body).unwrap();
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        reader.get_mut().write_all(response.as_bytes()).unwrap();
+        request_line
+    });
+
+    (address, handle)
+}
+
+#[test]
+fn dalle_prints_image_url() {
+    let (address, server) = mock_server(r#"{"created":0,"data":[{"url":"https://images.example/cat.png"}]}"#);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_oat"))
+        .args(["generate", "dalle", "a cat"])
+        .env("OPENAI_API_KEY", "test-key")
+        .env("OPENAI_BASE_URL", format!("{}/v1", address))
+        // Keep a local config (e.g. quiet = true) from affecting the output.
+        .env("OAT_HOME", env::temp_dir().join("oat-test-home"))
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "https://images.example/cat.png\n");
+    assert!(server.join().unwrap().starts_with("POST /v1/images/generations "));
+}